@@ -0,0 +1,559 @@
+//! ブートローダからカーネルへの引き渡し形式
+//!
+//! `Argument`のヘッダの直後にタグ付きレコードが並ぶ。
+//! 各レコードは`TagHeader`で始まり、次のレコードは8バイト境界から始まる。
+//! カーネルは知らない種類のレコードを`TagHeader::size`で読み飛ばす。
+#![no_std]
+
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+
+//"MIKANARG"
+pub const MAGIC: u64 = u64::from_le_bytes(*b"MIKANARG");
+//レイアウトに互換性のない変更をしたら上げる
+//レコードの種類を増やすだけなら上げなくてよい
+pub const VERSION: u32 = 2;
+
+#[repr(C)]
+pub struct Argument {
+    pub magic: u64,
+    pub version: u32,
+    //ヘッダとレコード全体のバイト数
+    pub size: u32,
+}
+
+impl Argument {
+    pub fn is_valid(&self) -> bool {
+        self.magic == MAGIC
+            && self.version == VERSION
+            && self.size as usize >= mem::size_of::<Argument>()
+    }
+
+    pub fn tags(&self) -> TagIter<'_> {
+        let start = self as *const Argument as *const u8;
+        //安全性はArgumentBuilderで作られていることに依存
+        unsafe {
+            TagIter {
+                next: start.add(mem::size_of::<Argument>()),
+                end: start.add(self.size as usize),
+                _argument: PhantomData,
+            }
+        }
+    }
+
+    pub fn find<T: Tag>(&self) -> Option<&T> {
+        self.tags().find_map(|tag| tag.downcast::<T>())
+    }
+
+    //同じ種類のレコードが複数あるもの用
+    pub fn find_all<'a, T: Tag + 'a>(&'a self) -> impl Iterator<Item = &'a T> {
+        self.tags().filter_map(|tag| tag.downcast::<T>())
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TagHeader {
+    pub tag_type: u32,
+    //ヘッダを含むレコードのバイト数
+    pub size: u32,
+}
+
+impl TagHeader {
+    pub fn new<T: Tag>() -> TagHeader {
+        TagHeader {
+            tag_type: T::TYPE,
+            size: mem::size_of::<T>() as u32,
+        }
+    }
+
+    pub fn downcast<T: Tag>(&self) -> Option<&T> {
+        if self.tag_type != T::TYPE || (self.size as usize) < mem::size_of::<T>() {
+            return None;
+        }
+        //Tagの実装はTagHeaderを先頭に持つrepr(C)の構造体
+        Some(unsafe { &*(self as *const TagHeader as *const T) })
+    }
+}
+
+//安全性: TagHeaderで始まるrepr(C)の構造体で、アラインメントは8以下、TYPEは他と重ならないこと
+#[allow(clippy::missing_safety_doc)]
+pub unsafe trait Tag: Copy {
+    const TYPE: u32;
+}
+
+pub struct TagIter<'a> {
+    next: *const u8,
+    end: *const u8,
+    _argument: PhantomData<&'a Argument>,
+}
+
+impl<'a> Iterator for TagIter<'a> {
+    type Item = &'a TagHeader;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = (self.end as usize).saturating_sub(self.next as usize);
+        if remaining < mem::size_of::<TagHeader>() {
+            return None;
+        }
+        let header = unsafe { &*(self.next as *const TagHeader) };
+        let size = align_up(header.size as usize);
+        if (header.size as usize) < mem::size_of::<TagHeader>() || size > remaining {
+            //壊れているのでこれ以上は読まない
+            self.next = self.end;
+            return None;
+        }
+        self.next = unsafe { self.next.add(size) };
+        Some(header)
+    }
+}
+
+//ブートローダ側でバッファにArgumentとレコードを書き込む
+pub struct ArgumentBuilder<'a> {
+    buffer: &'a mut [u64],
+    size: usize,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CapacityError;
+
+impl<'a> ArgumentBuilder<'a> {
+    //u64のスライスにすることで8バイト境界を保証する
+    pub fn new(buffer: &'a mut [u64]) -> ArgumentBuilder<'a> {
+        assert!(buffer.len() * 8 >= mem::size_of::<Argument>());
+        ArgumentBuilder {
+            buffer,
+            size: mem::size_of::<Argument>(),
+        }
+    }
+
+    pub fn push<T: Tag>(&mut self, tag: T) -> Result<(), CapacityError> {
+        let end = self.size + align_up(mem::size_of::<T>());
+        if end > self.buffer.len() * 8 {
+            return Err(CapacityError);
+        }
+        //範囲は確認済み、アラインメントはTagの実装に依存
+        unsafe {
+            let ptr = (self.buffer.as_mut_ptr() as *mut u8).add(self.size) as *mut T;
+            ptr::write(ptr, tag);
+        }
+        self.size = end;
+        Ok(())
+    }
+
+    pub fn finish(self) -> &'a Argument {
+        let argument = self.buffer.as_mut_ptr() as *mut Argument;
+        unsafe {
+            ptr::write(
+                argument,
+                Argument {
+                    magic: MAGIC,
+                    version: VERSION,
+                    size: self.size as u32,
+                },
+            );
+            &*argument
+        }
+    }
+}
+
+fn align_up(size: usize) -> usize {
+    (size + 7) & !7
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FrameBufferTag {
+    pub header: TagHeader,
+    pub frame_buffer: FrameBuffer,
+    pub frame_buffer_config: FrameBufferConfig,
+}
+
+unsafe impl Tag for FrameBufferTag {
+    const TYPE: u32 = 1;
+}
+
+impl FrameBufferTag {
+    pub fn new(frame_buffer: FrameBuffer, frame_buffer_config: FrameBufferConfig) -> Self {
+        FrameBufferTag {
+            header: TagHeader::new::<Self>(),
+            frame_buffer,
+            frame_buffer_config,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct MemoryMapTag {
+    pub header: TagHeader,
+    pub memory_map: MemoryMap,
+}
+
+unsafe impl Tag for MemoryMapTag {
+    const TYPE: u32 = 2;
+}
+
+impl MemoryMapTag {
+    pub fn new(memory_map: MemoryMap) -> Self {
+        MemoryMapTag {
+            header: TagHeader::new::<Self>(),
+            memory_map,
+        }
+    }
+}
+
+//RSDPの物理アドレス
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct AcpiTag {
+    pub header: TagHeader,
+    pub rsdp: u64,
+}
+
+unsafe impl Tag for AcpiTag {
+    const TYPE: u32 = 3;
+}
+
+impl AcpiTag {
+    pub fn new(rsdp: u64) -> Self {
+        AcpiTag {
+            header: TagHeader::new::<Self>(),
+            rsdp,
+        }
+    }
+}
+
+//UTF-8のカーネルコマンドライン(NUL終端なし)
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct CommandLineTag {
+    pub header: TagHeader,
+    pub data: *const u8,
+    pub len: usize,
+}
+
+unsafe impl Tag for CommandLineTag {
+    const TYPE: u32 = 4;
+}
+
+impl CommandLineTag {
+    pub fn new(data: *const u8, len: usize) -> Self {
+        CommandLineTag {
+            header: TagHeader::new::<Self>(),
+            data,
+            len,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SymbolTableTag {
+    pub header: TagHeader,
+    pub symbol_table: SymbolTable,
+}
+
+unsafe impl Tag for SymbolTableTag {
+    const TYPE: u32 = 5;
+}
+
+impl SymbolTableTag {
+    pub fn new(symbol_table: SymbolTable) -> Self {
+        SymbolTableTag {
+            header: TagHeader::new::<Self>(),
+            symbol_table,
+        }
+    }
+}
+
+//カーネルと一緒に読み込んだファイル(initrdやモジュール)
+//ファイルの数だけ並ぶ、名前はUTF-8でNUL終端なし
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct ModuleTag {
+    pub header: TagHeader,
+    pub data: *const u8,
+    pub len: usize,
+    pub name: *const u8,
+    pub name_len: usize,
+}
+
+unsafe impl Tag for ModuleTag {
+    const TYPE: u32 = 6;
+}
+
+impl ModuleTag {
+    pub fn new(data: *const u8, len: usize, name: *const u8, name_len: usize) -> Self {
+        ModuleTag {
+            header: TagHeader::new::<Self>(),
+            data,
+            len,
+            name,
+            name_len,
+        }
+    }
+}
+
+//ブートローダが読み込んだカーネルイメージのSHA-256
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct KernelDigestTag {
+    pub header: TagHeader,
+    pub sha256: [u8; 32],
+}
+
+unsafe impl Tag for KernelDigestTag {
+    const TYPE: u32 = 7;
+}
+
+impl KernelDigestTag {
+    pub fn new(sha256: [u8; 32]) -> Self {
+        KernelDigestTag {
+            header: TagHeader::new::<Self>(),
+            sha256,
+        }
+    }
+}
+
+//ブートローダが作ったページテーブル
+//低位はidentity mapのまま、物理メモリ全体をphysical_offsetからも写している
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct PagingTag {
+    pub header: TagHeader,
+    //CR3に入っている物理アドレス
+    pub page_table: u64,
+    pub physical_offset: u64,
+    pub physical_memory_size: u64,
+    pub kernel_physical_base: u64,
+    pub kernel_virtual_base: u64,
+    pub kernel_size: u64,
+}
+
+unsafe impl Tag for PagingTag {
+    const TYPE: u32 = 8;
+}
+
+impl PagingTag {
+    pub fn new(
+        page_table: u64,
+        physical_offset: u64,
+        physical_memory_size: u64,
+        kernel_physical_base: u64,
+        kernel_virtual_base: u64,
+        kernel_size: u64,
+    ) -> Self {
+        PagingTag {
+            header: TagHeader::new::<Self>(),
+            page_table,
+            physical_offset,
+            physical_memory_size,
+            kernel_physical_base,
+            kernel_virtual_base,
+            kernel_size,
+        }
+    }
+}
+
+//UEFIのEFI_RUNTIME_SERVICESの物理アドレス
+//SetVirtualAddressMapは呼んでいないのでidentity mapのまま呼び出す
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RuntimeServicesTag {
+    pub header: TagHeader,
+    pub runtime_services: u64,
+}
+
+unsafe impl Tag for RuntimeServicesTag {
+    const TYPE: u32 = 9;
+}
+
+impl RuntimeServicesTag {
+    pub fn new(runtime_services: u64) -> Self {
+        RuntimeServicesTag {
+            header: TagHeader::new::<Self>(),
+            runtime_services,
+        }
+    }
+}
+
+//SMBIOSのエントリポイントの物理アドレス
+//SMBIOS 3.0の"_SM3_"があればそちらを、無ければ"_SM_"を指す
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SmbiosTag {
+    pub header: TagHeader,
+    pub entry_point: u64,
+}
+
+unsafe impl Tag for SmbiosTag {
+    const TYPE: u32 = 10;
+}
+
+impl SmbiosTag {
+    pub fn new(entry_point: u64) -> Self {
+        SmbiosTag {
+            header: TagHeader::new::<Self>(),
+            entry_point,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FrameBuffer {
+    pub base: *mut u8,
+    pub size: usize,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FrameBufferConfig {
+    pub pixels_per_scan_line: usize,
+    pub horizontal_resolution: usize,
+    pub vertical_resolution: usize,
+    pub pixel_format: PixelFormat,
+}
+
+//Bitmaskは1ピクセル4バイトで、各色の位置をマスクで表す
+#[derive(Copy, Clone)]
+#[repr(u32)]
+pub enum PixelFormat {
+    Rgb,
+    Bgr,
+    Bitmask { red: u32, green: u32, blue: u32 },
+}
+
+//カーネルの.symtabと.strtabのコピー
+//シンボルの値はリンク時のアドレスなのでload_biasを足して使う
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SymbolTable {
+    pub symbols: *const u8,
+    pub symbols_size: usize,
+    pub strings: *const u8,
+    pub strings_size: usize,
+    pub load_bias: u64,
+}
+
+//exit_boot_services時点のメモリマップ
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct MemoryMap {
+    pub descriptors: *const MemoryDescriptor,
+    pub len: usize,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct MemoryDescriptor {
+    pub memory_type: MemoryType,
+    pub physical_start: u64,
+    pub page_count: u64,
+    pub attribute: u64,
+}
+
+//UEFIのEFI_MEMORY_TYPEと同じ値
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum MemoryType {
+    Reserved = 0,
+    LoaderCode,
+    LoaderData,
+    BootServicesCode,
+    BootServicesData,
+    RuntimeServicesCode,
+    RuntimeServicesData,
+    Conventional,
+    Unusable,
+    AcpiReclaim,
+    AcpiNonVolatile,
+    Mmio,
+    MmioPortSpace,
+    PalCode,
+    PersistentMemory,
+    Unknown = 0xffff_ffff,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //finishした後にバッファを書き換えて読み直す用
+    fn argument(buffer: &[u64]) -> &Argument {
+        unsafe { &*(buffer.as_ptr() as *const Argument) }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut buffer = [0; 64];
+        let mut builder = ArgumentBuilder::new(&mut buffer);
+        builder.push(AcpiTag::new(0xe0000)).unwrap();
+        builder.push(KernelDigestTag::new([0xab; 32])).unwrap();
+        let argument = builder.finish();
+        assert!(argument.is_valid());
+        assert_eq!(argument.find::<AcpiTag>().unwrap().rsdp, 0xe0000);
+        assert_eq!(
+            argument.find::<KernelDigestTag>().unwrap().sha256,
+            [0xab; 32]
+        );
+        assert!(argument.find::<SmbiosTag>().is_none());
+        assert_eq!(argument.tags().count(), 2);
+    }
+
+    #[test]
+    fn find_all_returns_every_tag() {
+        let names = [b"initrd".as_ref(), b"hankaku.bin".as_ref()];
+        let mut buffer = [0; 64];
+        let mut builder = ArgumentBuilder::new(&mut buffer);
+        for (i, name) in names.iter().enumerate() {
+            builder
+                .push(ModuleTag::new(ptr::null(), i, name.as_ptr(), name.len()))
+                .unwrap();
+        }
+        builder.push(AcpiTag::new(0)).unwrap();
+        let argument = builder.finish();
+        let modules: [_; 2] = {
+            let mut modules = argument.find_all::<ModuleTag>();
+            let first = *modules.next().unwrap();
+            let second = *modules.next().unwrap();
+            assert!(modules.next().is_none());
+            [first, second]
+        };
+        for (i, module) in modules.iter().enumerate() {
+            assert_eq!(module.len, i);
+            assert_eq!(module.name, names[i].as_ptr());
+            assert_eq!(module.name_len, names[i].len());
+        }
+    }
+
+    #[test]
+    fn push_fails_when_full() {
+        let mut buffer = [0; 4];
+        let mut builder = ArgumentBuilder::new(&mut buffer);
+        builder.push(AcpiTag::new(0)).unwrap();
+        assert!(builder.push(AcpiTag::new(0)).is_err());
+        assert_eq!(builder.finish().tags().count(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let mut buffer = [0; 8];
+        ArgumentBuilder::new(&mut buffer).finish();
+        buffer[0] = u64::from_le_bytes(*b"NOTMIKAN");
+        assert!(!argument(&buffer).is_valid());
+    }
+
+    #[test]
+    fn rejects_bad_version() {
+        let mut buffer = [0; 8];
+        ArgumentBuilder::new(&mut buffer).finish();
+        //versionはmagicの直後、sizeは上位32ビット
+        buffer[1] = (buffer[1] & !0xffff_ffff) | (VERSION as u64 + 1);
+        assert!(!argument(&buffer).is_valid());
+        buffer[1] = (buffer[1] & !0xffff_ffff) | VERSION as u64;
+        assert!(argument(&buffer).is_valid());
+    }
+}
@@ -0,0 +1,81 @@
+extern crate alloc;
+
+use crate::file;
+use alloc::string::String;
+use core::arch::x86_64::_rdtsc;
+use core::fmt;
+use core::fmt::Write;
+use uefi::prelude::*;
+use uefi::proto::console::text::Output;
+use uefi::proto::media::file::Directory;
+
+//UEFIのコンソールに出した行を\bootlog.txtにも残す
+//exit_boot_servicesの後はファイルに書けないのでその前にflushする
+pub struct BootLog<'a, 'b> {
+    stdout: &'a mut Output<'b>,
+    text: String,
+    //TSCの値
+    start: u64,
+    phase_start: u64,
+}
+
+impl<'a, 'b> BootLog<'a, 'b> {
+    pub const PATH: &'static str = "\\bootlog.txt";
+
+    pub fn new(stdout: &'a mut Output<'b>) -> BootLog<'a, 'b> {
+        let now = rdtsc();
+        BootLog {
+            stdout,
+            text: String::new(),
+            start: now,
+            phase_start: now,
+        }
+    }
+
+    //メニューなど画面を直接操作するもの用、ここに書いたものは残らない
+    pub fn stdout(&mut self) -> &mut Output<'b> {
+        &mut *self.stdout
+    }
+
+    //画面には出さずにファイルにだけ残す
+    pub fn file_only(&mut self) -> &mut String {
+        &mut self.text
+    }
+
+    //前の区切りからのTSCのカウント数を残す
+    pub fn phase(&mut self, name: &str) {
+        let now = rdtsc();
+        writeln!(
+            self.text,
+            "[tsc] {}: {} ticks",
+            name,
+            now.wrapping_sub(self.phase_start)
+        )
+        .unwrap();
+        self.phase_start = now;
+    }
+
+    //何度呼んでもよい、その時点までの内容でファイルを書き換える
+    pub fn flush(&mut self, root_dir: &mut Directory) -> Result<(), Status> {
+        let mut text = self.text.clone();
+        writeln!(
+            text,
+            "[tsc] total: {} ticks",
+            rdtsc().wrapping_sub(self.start)
+        )
+        .unwrap();
+        file::write_file(root_dir, BootLog::PATH, text.as_bytes())
+    }
+}
+
+impl fmt::Write for BootLog<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.text.push_str(s);
+        self.stdout.write_str(s)
+    }
+}
+
+fn rdtsc() -> u64 {
+    //安全性: x86_64なら必ずある命令
+    unsafe { _rdtsc() }
+}
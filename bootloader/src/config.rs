@@ -0,0 +1,209 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use uefi::proto::console::gop::PixelFormat;
+
+//\boot.cfgの内容
+//書かれていない項目は今までの動作と同じ値になる
+//
+//kernel=\kernel.elf
+//resolution=1024x768 (autoなら一番大きいモード)
+//pixel_format=auto (rgb, bgr, bitmask)
+//memmap=yes
+//cmdline=
+//timeout=0 (0より大きくカーネルが複数あればメニューを出す)
+//stack_size=256 (KiB、4KiB単位に切り上げる、64MiBまで)
+//entry=\kernel-debug.elf (何行でも書ける、無ければ\にある*.elfをメニューに出す)
+//initrd=\initrd (noneなら読まない)
+//module=\fonts\hankaku.bin (何行でも書ける)
+pub struct Config {
+    pub kernel_path: String,
+    //メニューではkernel_pathと同じものを最初に選んでおく
+    pub entries: Vec<String>,
+    //ファイルが無ければ読まない
+    pub initrd_path: Option<String>,
+    pub module_paths: Vec<String>,
+    //Noneなら使えるモードのうち一番大きいもの
+    pub resolution: Option<(usize, usize)>,
+    pub pixel_format: Option<PixelFormat>,
+    pub dump_memory_map: bool,
+    pub command_line: String,
+    //秒
+    pub timeout: u64,
+    //KiB
+    pub stack_size: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            kernel_path: "\\kernel.elf".to_string(),
+            entries: Vec::new(),
+            initrd_path: Some("\\initrd".to_string()),
+            module_paths: Vec::new(),
+            resolution: None,
+            pixel_format: None,
+            dump_memory_map: true,
+            command_line: String::new(),
+            timeout: 0,
+            stack_size: 256,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigErrorKind {
+    MissingEquals,
+    UnknownKey(String),
+    InvalidValue(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub line: usize,
+    pub kind: ConfigErrorKind,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ConfigErrorKind::MissingEquals => write!(f, "line {}: expected key=value", self.line),
+            ConfigErrorKind::UnknownKey(key) => {
+                write!(f, "line {}: unknown key \"{}\"", self.line, key)
+            }
+            ConfigErrorKind::InvalidValue(key) => {
+                write!(f, "line {}: invalid value for \"{}\"", self.line, key)
+            }
+        }
+    }
+}
+
+impl Config {
+    pub const PATH: &'static str = "\\boot.cfg";
+    //KiB
+    pub const MAX_STACK_SIZE: u64 = 64 * 1024;
+
+    //一行に一つkey=value、#から行末まではコメント
+    pub fn parse(text: &str) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            let line = match line.find('#') {
+                Some(comment) => &line[..comment],
+                None => line,
+            }
+            .trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = match line.find('=') {
+                Some(equals) => (line[..equals].trim(), line[equals + 1..].trim()),
+                None => {
+                    return Err(ConfigError {
+                        line: line_number,
+                        kind: ConfigErrorKind::MissingEquals,
+                    })
+                }
+            };
+            let invalid_value = || ConfigError {
+                line: line_number,
+                kind: ConfigErrorKind::InvalidValue(key.to_string()),
+            };
+            match key {
+                "kernel" => {
+                    if value.is_empty() {
+                        return Err(invalid_value());
+                    }
+                    config.kernel_path = Config::parse_path(value);
+                }
+                "entry" => {
+                    if value.is_empty() {
+                        return Err(invalid_value());
+                    }
+                    config.entries.push(Config::parse_path(value));
+                }
+                "initrd" => {
+                    config.initrd_path = match value {
+                        "" => return Err(invalid_value()),
+                        "none" => None,
+                        _ => Some(Config::parse_path(value)),
+                    }
+                }
+                "module" => {
+                    if value.is_empty() {
+                        return Err(invalid_value());
+                    }
+                    config.module_paths.push(Config::parse_path(value));
+                }
+                "resolution" => {
+                    config.resolution = Config::parse_resolution(value).ok_or_else(invalid_value)?
+                }
+                "pixel_format" => {
+                    config.pixel_format =
+                        Config::parse_pixel_format(value).ok_or_else(invalid_value)?
+                }
+                "memmap" => {
+                    config.dump_memory_map = Config::parse_bool(value).ok_or_else(invalid_value)?
+                }
+                "cmdline" => config.command_line = value.to_string(),
+                "timeout" => config.timeout = value.parse().map_err(|_| invalid_value())?,
+                "stack_size" => {
+                    config.stack_size = value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|&size| size > 0 && size <= Config::MAX_STACK_SIZE)
+                        .ok_or_else(invalid_value)?
+                }
+                _ => {
+                    return Err(ConfigError {
+                        line: line_number,
+                        kind: ConfigErrorKind::UnknownKey(key.to_string()),
+                    })
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    //UEFIのパス区切りは\なので/も受け付けて変換する
+    fn parse_path(value: &str) -> String {
+        let path = value.replace('/', "\\");
+        if path.starts_with('\\') {
+            path
+        } else {
+            alloc::format!("\\{}", path)
+        }
+    }
+
+    //"1024x768"、"auto"ならNone
+    fn parse_resolution(value: &str) -> Option<Option<(usize, usize)>> {
+        if value == "auto" {
+            return Some(None);
+        }
+        let separator = value.find('x')?;
+        let horizontal = value[..separator].trim().parse().ok()?;
+        let vertical = value[separator + 1..].trim().parse().ok()?;
+        Some(Some((horizontal, vertical)))
+    }
+
+    //"auto"ならNone
+    fn parse_pixel_format(value: &str) -> Option<Option<PixelFormat>> {
+        match value {
+            "auto" => Some(None),
+            "rgb" => Some(Some(PixelFormat::Rgb)),
+            "bgr" => Some(Some(PixelFormat::Bgr)),
+            "bitmask" => Some(Some(PixelFormat::Bitmask)),
+            _ => None,
+        }
+    }
+
+    fn parse_bool(value: &str) -> Option<bool> {
+        match value {
+            "yes" | "true" | "on" | "1" => Some(true),
+            "no" | "false" | "off" | "0" => Some(false),
+            _ => None,
+        }
+    }
+}
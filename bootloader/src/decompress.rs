@@ -0,0 +1,362 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use miniz_oxide::inflate;
+
+//カーネルイメージの形式を先頭のマジックナンバーで判別する
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    //圧縮されていない(ELF)
+    Plain,
+    Gzip,
+    //zlib形式のdeflate
+    Zlib,
+    Lz4Frame,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Plain => write!(f, "plain"),
+            Format::Gzip => write!(f, "gzip"),
+            Format::Zlib => write!(f, "zlib"),
+            Format::Lz4Frame => write!(f, "LZ4"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecompressError {
+    Truncated,
+    InvalidHeader,
+    Unsupported(&'static str),
+    CorruptData,
+    ChecksumMismatch,
+    SizeMismatch,
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecompressError::Truncated => write!(f, "compressed data is truncated"),
+            DecompressError::InvalidHeader => write!(f, "invalid header"),
+            DecompressError::Unsupported(feature) => write!(f, "unsupported {}", feature),
+            DecompressError::CorruptData => write!(f, "compressed data is corrupt"),
+            DecompressError::ChecksumMismatch => write!(f, "checksum mismatch"),
+            DecompressError::SizeMismatch => write!(f, "decompressed size mismatch"),
+        }
+    }
+}
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const LZ4_FRAME_MAGIC: [u8; 4] = [0x04, 0x22, 0x4d, 0x18];
+//ヘッダの展開後の大きさは信用せず、先に確保するのは入力のこの倍数まで
+const LZ4_RESERVE_RATIO: usize = 8;
+
+pub fn detect(data: &[u8]) -> Format {
+    if data.starts_with(&GZIP_MAGIC) {
+        Format::Gzip
+    } else if data.starts_with(&LZ4_FRAME_MAGIC) {
+        Format::Lz4Frame
+    } else if data.len() >= 2
+        && data[0] & 0x0f == 8
+        && (u16::from(data[0]) << 8 | u16::from(data[1])) % 31 == 0
+    {
+        //CMFの下位4bitがdeflate(8)で、CMFとFLGを合わせた値が31の倍数
+        Format::Zlib
+    } else {
+        Format::Plain
+    }
+}
+
+//圧縮されていなければNone
+//展開先はグローバルアロケータ(LOADER_DATAのプール)から確保する
+pub fn decompress(data: &[u8]) -> Result<Option<Vec<u8>>, DecompressError> {
+    match detect(data) {
+        Format::Plain => Ok(None),
+        Format::Gzip => decompress_gzip(data).map(Some),
+        Format::Zlib => inflate::decompress_to_vec_zlib(data)
+            .map(Some)
+            .map_err(|_| DecompressError::CorruptData),
+        Format::Lz4Frame => decompress_lz4_frame(data).map(Some),
+    }
+}
+
+//RFC 1952
+fn decompress_gzip(data: &[u8]) -> Result<Vec<u8>, DecompressError> {
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+
+    let mut reader = Reader::new(data);
+    reader.take(2)?;
+    //deflate以外は定義されていない
+    if reader.u8()? != 8 {
+        return Err(DecompressError::InvalidHeader);
+    }
+    let flags = reader.u8()?;
+    //MTIME、XFL、OS
+    reader.take(6)?;
+    if flags & FEXTRA != 0 {
+        let len = reader.u16()?;
+        reader.take(len as usize)?;
+    }
+    if flags & FNAME != 0 {
+        reader.skip_c_string()?;
+    }
+    if flags & FCOMMENT != 0 {
+        reader.skip_c_string()?;
+    }
+    if flags & FHCRC != 0 {
+        reader.take(2)?;
+    }
+
+    let rest = reader.rest();
+    if rest.len() < 8 {
+        return Err(DecompressError::Truncated);
+    }
+    let (deflate, trailer) = rest.split_at(rest.len() - 8);
+    let output = inflate::decompress_to_vec(deflate).map_err(|_| DecompressError::CorruptData)?;
+    let mut trailer = Reader::new(trailer);
+    let crc = trailer.u32()?;
+    let size = trailer.u32()?;
+    if size != output.len() as u32 {
+        return Err(DecompressError::SizeMismatch);
+    }
+    if crc != crc32(&output) {
+        return Err(DecompressError::ChecksumMismatch);
+    }
+    Ok(output)
+}
+
+//https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md
+fn decompress_lz4_frame(data: &[u8]) -> Result<Vec<u8>, DecompressError> {
+    let mut reader = Reader::new(data);
+    reader.take(4)?;
+    let descriptor_start = reader.position;
+    let flags = reader.u8()?;
+    //BD、ブロックの最大サイズは展開先を一つにまとめるので使わない
+    reader.u8()?;
+    if flags >> 6 != 0b01 {
+        return Err(DecompressError::InvalidHeader);
+    }
+    let block_checksum = flags & 0x10 != 0;
+    let has_content_size = flags & 0x08 != 0;
+    let content_checksum = flags & 0x04 != 0;
+    if flags & 0x01 != 0 {
+        return Err(DecompressError::Unsupported("LZ4 dictionary"));
+    }
+    let content_size = if has_content_size {
+        Some(reader.u64()?)
+    } else {
+        None
+    };
+    let descriptor = &data[descriptor_start..reader.position];
+    if reader.u8()? != (xxh32(descriptor, 0) >> 8) as u8 {
+        return Err(DecompressError::ChecksumMismatch);
+    }
+
+    //ブロックが独立していなくても展開先が一続きなら前のブロックをそのまま参照できる
+    let reserve = content_size
+        .unwrap_or(0)
+        .min(data.len().saturating_mul(LZ4_RESERVE_RATIO) as u64);
+    let mut output = Vec::with_capacity(reserve as usize);
+    loop {
+        let block_size = reader.u32()?;
+        if block_size == 0 {
+            break;
+        }
+        let uncompressed = block_size & 0x8000_0000 != 0;
+        let block = reader.take((block_size & 0x7fff_ffff) as usize)?;
+        if block_checksum && reader.u32()? != xxh32(block, 0) {
+            return Err(DecompressError::ChecksumMismatch);
+        }
+        if uncompressed {
+            output.extend_from_slice(block);
+        } else {
+            decompress_lz4_block(block, &mut output)?;
+        }
+    }
+    if content_checksum && reader.u32()? != xxh32(&output, 0) {
+        return Err(DecompressError::ChecksumMismatch);
+    }
+    if let Some(content_size) = content_size {
+        if content_size != output.len() as u64 {
+            return Err(DecompressError::SizeMismatch);
+        }
+    }
+    Ok(output)
+}
+
+//https://github.com/lz4/lz4/blob/dev/doc/lz4_Block_format.md
+fn decompress_lz4_block(block: &[u8], output: &mut Vec<u8>) -> Result<(), DecompressError> {
+    let mut reader = Reader::new(block);
+    loop {
+        let token = reader.u8()?;
+        let literal_len = reader.lz4_length(token >> 4)?;
+        output.extend_from_slice(reader.take(literal_len)?);
+        //最後のシーケンスはリテラルだけ
+        if reader.rest().is_empty() {
+            return Ok(());
+        }
+        let offset = reader.u16()? as usize;
+        if offset == 0 || offset > output.len() {
+            return Err(DecompressError::CorruptData);
+        }
+        let match_len = reader.lz4_length(token & 0x0f)? + 4;
+        //重なっていることがあるので1バイトずつコピーする
+        let start = output.len() - offset;
+        output.reserve(match_len);
+        for i in 0..match_len {
+            let byte = output[start + i];
+            output.push(byte);
+        }
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader { data, position: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecompressError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(DecompressError::Truncated)?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+
+    fn u8(&mut self) -> Result<u8, DecompressError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, DecompressError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, DecompressError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecompressError> {
+        let low = self.u32()? as u64;
+        let high = self.u32()? as u64;
+        Ok(high << 32 | low)
+    }
+
+    fn skip_c_string(&mut self) -> Result<(), DecompressError> {
+        while self.u8()? != 0 {}
+        Ok(())
+    }
+
+    //15なら255未満のバイトが来るまで足していく
+    fn lz4_length(&mut self, nibble: u8) -> Result<usize, DecompressError> {
+        let mut len = nibble as usize;
+        if nibble == 15 {
+            loop {
+                let byte = self.u8()?;
+                len += byte as usize;
+                if byte != 255 {
+                    break;
+                }
+            }
+        }
+        Ok(len)
+    }
+}
+
+//gzipのCRC-32(多項式0xedb88320)
+fn crc32(data: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut crc = i as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+        *entry = crc;
+    }
+    !data.iter().fold(!0u32, |crc, &byte| {
+        table[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+//LZ4フレームのチェックサム
+fn xxh32(data: &[u8], seed: u32) -> u32 {
+    const PRIME1: u32 = 0x9e37_79b1;
+    const PRIME2: u32 = 0x85eb_ca77;
+    const PRIME3: u32 = 0xc2b2_ae3d;
+    const PRIME4: u32 = 0x27d4_eb2f;
+    const PRIME5: u32 = 0x1656_67b1;
+
+    let read = |bytes: &[u8]| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let round = |acc: u32, lane: u32| {
+        acc.wrapping_add(lane.wrapping_mul(PRIME2))
+            .rotate_left(13)
+            .wrapping_mul(PRIME1)
+    };
+
+    let mut stripes = data.chunks_exact(16);
+    let mut hash = if data.len() >= 16 {
+        let mut acc = [
+            seed.wrapping_add(PRIME1).wrapping_add(PRIME2),
+            seed.wrapping_add(PRIME2),
+            seed,
+            seed.wrapping_sub(PRIME1),
+        ];
+        for stripe in &mut stripes {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = round(*acc, read(&stripe[i * 4..]));
+            }
+        }
+        acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18))
+    } else {
+        seed.wrapping_add(PRIME5)
+    };
+    hash = hash.wrapping_add(data.len() as u32);
+
+    let rest = stripes.remainder();
+    let mut words = rest.chunks_exact(4);
+    for word in &mut words {
+        hash = hash
+            .wrapping_add(read(word).wrapping_mul(PRIME3))
+            .rotate_left(17)
+            .wrapping_mul(PRIME4);
+    }
+    for &byte in words.remainder() {
+        hash = hash
+            .wrapping_add((byte as u32).wrapping_mul(PRIME5))
+            .rotate_left(11)
+            .wrapping_mul(PRIME1);
+    }
+
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(PRIME2);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(PRIME3);
+    hash ^ (hash >> 16)
+}
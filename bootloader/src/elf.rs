@@ -1,32 +1,180 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use core::fmt;
 use core::iter::Iterator;
+use core::mem;
+use core::ptr;
 
-pub struct Elf {
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ElfError {
+    TooSmall { size: usize },
+    InvalidMagic,
+    UnsupportedClass(u8),
+    UnsupportedEndian(u8),
+    UnsupportedMachine(u16),
+    UnsupportedType(u16),
+    InvalidProgramHeaderSize(u16),
+    ProgramHeadersOutOfBounds,
+    InvalidSectionHeaderSize(u16),
+    SectionHeadersOutOfBounds,
+    SectionOutOfBounds(u16),
+    NoLoadSegment,
+    SegmentOutOfBounds { p_offset: u64, p_filesz: u64 },
+    InvalidSegmentSize { p_filesz: u64, p_memsz: u64 },
+    SegmentAddressOverflow { p_vaddr: u64, p_memsz: u64 },
+    ImageTooLarge,
+    InvalidDynamicSection,
+    UnsupportedRelocation(u32),
+    RelocationOutOfBounds(u64),
+    UndefinedSymbol(u32),
+}
+
+impl fmt::Display for ElfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ElfError::TooSmall { size } => write!(f, "file is too small ({} bytes)", size),
+            ElfError::InvalidMagic => write!(f, "not an ELF file (bad magic)"),
+            ElfError::UnsupportedClass(class) => {
+                write!(f, "unsupported ELF class {} (expected ELFCLASS64)", class)
+            }
+            ElfError::UnsupportedEndian(data) => {
+                write!(
+                    f,
+                    "unsupported data encoding {} (expected little-endian)",
+                    data
+                )
+            }
+            ElfError::UnsupportedMachine(machine) => {
+                write!(f, "unsupported machine {:#x} (expected x86_64)", machine)
+            }
+            ElfError::UnsupportedType(ty) => {
+                write!(
+                    f,
+                    "unsupported ELF type {} (expected ET_EXEC or ET_DYN)",
+                    ty
+                )
+            }
+            ElfError::InvalidProgramHeaderSize(size) => {
+                write!(f, "invalid program header entry size {}", size)
+            }
+            ElfError::ProgramHeadersOutOfBounds => {
+                write!(f, "program headers lie outside of the file (truncated?)")
+            }
+            ElfError::InvalidSectionHeaderSize(size) => {
+                write!(f, "invalid section header entry size {}", size)
+            }
+            ElfError::SectionHeadersOutOfBounds => {
+                write!(f, "section headers lie outside of the file (truncated?)")
+            }
+            ElfError::SectionOutOfBounds(index) => {
+                write!(f, "section {} lies outside of the file", index)
+            }
+            ElfError::NoLoadSegment => write!(f, "no PT_LOAD segment"),
+            ElfError::SegmentOutOfBounds { p_offset, p_filesz } => write!(
+                f,
+                "segment at offset {:#x} (size {:#x}) lies outside of the file",
+                p_offset, p_filesz
+            ),
+            ElfError::InvalidSegmentSize { p_filesz, p_memsz } => write!(
+                f,
+                "segment file size {:#x} exceeds memory size {:#x}",
+                p_filesz, p_memsz
+            ),
+            ElfError::SegmentAddressOverflow { p_vaddr, p_memsz } => write!(
+                f,
+                "segment at {:#x} (size {:#x}) overflows the address space",
+                p_vaddr, p_memsz
+            ),
+            ElfError::ImageTooLarge => write!(f, "loadable segments span too many pages"),
+            ElfError::InvalidDynamicSection => write!(f, "malformed PT_DYNAMIC segment"),
+            ElfError::UnsupportedRelocation(ty) => {
+                write!(f, "unsupported relocation type {}", ty)
+            }
+            ElfError::RelocationOutOfBounds(offset) => {
+                write!(f, "relocation at {:#x} lies outside of the image", offset)
+            }
+            ElfError::UndefinedSymbol(index) => {
+                write!(f, "relocation refers to undefined symbol {}", index)
+            }
+        }
+    }
+}
+
+pub struct Elf<'a> {
+    buffer: &'a [u8],
     elf_header: ElfHeader,
     program_headers: Vec<ProgramHeader>,
+    section_headers: Vec<SectionHeader>,
+    page_count: usize,
 }
 
-impl Elf {
-    pub fn new(buffer: &mut [u8]) -> Elf {
-        let elf_header = ElfHeader::new(buffer);
+impl<'a> Elf<'a> {
+    pub fn new(buffer: &'a [u8]) -> Result<Elf<'a>, ElfError> {
+        let elf_header = ElfHeader::new(buffer)?;
+
+        //e_phoff + e_phnum * e_phentsize がバッファに収まるか確認
+        let program_headers_end = (elf_header.e_phnum as u64)
+            .checked_mul(elf_header.e_phentsize as u64)
+            .and_then(|size| size.checked_add(elf_header.e_phoff))
+            .ok_or(ElfError::ProgramHeadersOutOfBounds)?;
+        if program_headers_end > buffer.len() as u64 {
+            return Err(ElfError::ProgramHeadersOutOfBounds);
+        }
 
         let mut program_headers = Vec::new();
-        let program_header_addr = buffer.as_ptr() as u64 + elf_header.e_phoff;
-        for i in 0..elf_header.e_phnum {
-            let program_header_ptr = (program_header_addr
-                + i as u64 * elf_header.e_phentsize as u64)
-                as *const ProgramHeader;
-            let program_header = unsafe { *program_header_ptr };
+        for i in 0..elf_header.e_phnum as u64 {
+            let offset = elf_header.e_phoff + i * elf_header.e_phentsize as u64;
+            let program_header =
+                read::<ProgramHeader>(buffer, offset).ok_or(ElfError::ProgramHeadersOutOfBounds)?;
             program_headers.push(program_header);
         }
-        Elf {
+        if !program_headers.iter().any(|p| p.type_is_load()) {
+            return Err(ElfError::NoLoadSegment);
+        }
+        //PT_DYNAMICもファイルから読むので同じように確認する
+        for program_header in program_headers
+            .iter()
+            .filter(|p| p.type_is_load() || p.type_is_dynamic())
+        {
+            program_header.validate(buffer.len())?;
+        }
+        let page_count = page_count(&program_headers).ok_or(ElfError::ImageTooLarge)?;
+
+        //セクションヘッダはstripされていると無いこともある
+        let mut section_headers = Vec::new();
+        if elf_header.e_shnum != 0
+            && (elf_header.e_shentsize as usize) < mem::size_of::<SectionHeader>()
+        {
+            return Err(ElfError::InvalidSectionHeaderSize(elf_header.e_shentsize));
+        }
+        for i in 0..elf_header.e_shnum {
+            let offset = (i as u64)
+                .checked_mul(elf_header.e_shentsize as u64)
+                .and_then(|offset| offset.checked_add(elf_header.e_shoff))
+                .ok_or(ElfError::SectionHeadersOutOfBounds)?;
+            let section_header =
+                read::<SectionHeader>(buffer, offset).ok_or(ElfError::SectionHeadersOutOfBounds)?;
+            if !section_header.is_within(buffer.len()) {
+                return Err(ElfError::SectionOutOfBounds(i));
+            }
+            section_headers.push(section_header);
+        }
+
+        Ok(Elf {
+            buffer,
             elf_header,
             program_headers,
-        }
+            section_headers,
+            page_count,
+        })
+    }
+
+    pub fn is_position_independent(&self) -> bool {
+        self.elf_header.e_type == ElfHeader::TYPE_DYN
     }
 
+    //ページ境界に切り下げたリンク時の先頭アドレス
     pub fn calculate_base_addr(&self) -> u64 {
         let mut base_addr = u64::MAX;
         for program_header in self.program_headers.iter() {
@@ -37,33 +185,254 @@ impl Elf {
                 base_addr = program_header.p_vaddr;
             }
         }
-        base_addr
+        base_addr & !0xfff
     }
 
+    //計算はnewで済ませている
     pub fn calculate_page_count(&self) -> usize {
-        let mut base_addr = u64::MAX;
-        let mut last_addr = 0;
+        self.page_count
+    }
+
+    //安全性: base_addrからcalculate_page_countページ確保済みで、ET_EXECならbase_addrがcalculate_base_addrであること
+    pub unsafe fn load_segments(&self, base_addr: u64) {
+        let bias = self.load_bias(base_addr);
         for program_header in self.program_headers.iter() {
             if !program_header.type_is_load() {
                 continue;
             }
-            if base_addr > program_header.p_vaddr {
-                base_addr = program_header.p_vaddr;
-            }
-            if last_addr < program_header.p_vaddr + program_header.p_memsz {
-                last_addr = program_header.p_vaddr + program_header.p_memsz;
+            let addr = program_header.p_vaddr.wrapping_add(bias) as *mut u8;
+            let offset = program_header.p_offset as usize;
+            let file_size = program_header.p_filesz as usize;
+            let memory_size = program_header.p_memsz as usize;
+            //範囲はnewで確認済み
+            ptr::copy_nonoverlapping(self.buffer[offset..].as_ptr(), addr, file_size);
+            ptr::write_bytes(addr.add(file_size), 0, memory_size - file_size);
+        }
+    }
+
+    //R_X86_64_RELATIVEとR_X86_64_64だけ扱う
+    //安全性: 先にload_segments(base_addr)を呼んでいること
+    pub unsafe fn apply_relocations(
+        &self,
+        base_addr: u64,
+        virtual_base_addr: u64,
+    ) -> Result<(), ElfError> {
+        let dynamic = match self.dynamic_info()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(()),
+        };
+        if dynamic.rela_size == 0 {
+            return Ok(());
+        }
+        if dynamic.rela_entry_size < mem::size_of::<Rela>() as u64 {
+            return Err(ElfError::InvalidDynamicSection);
+        }
+
+        let bias = self.load_bias(virtual_base_addr);
+        let load_bias = self.load_bias(base_addr);
+        let image_start = self.calculate_base_addr();
+        let image_end = image_start + self.calculate_page_count() as u64 * 0x1000;
+        let rela_offset = self
+            .file_offset(dynamic.rela)
+            .ok_or(ElfError::InvalidDynamicSection)?;
+        for i in 0..dynamic.rela_size / dynamic.rela_entry_size {
+            let offset = i
+                .checked_mul(dynamic.rela_entry_size)
+                .and_then(|offset| offset.checked_add(rela_offset))
+                .ok_or(ElfError::InvalidDynamicSection)?;
+            let rela = read::<Rela>(self.buffer, offset).ok_or(ElfError::InvalidDynamicSection)?;
+            let value = match rela.relocation_type() {
+                Rela::R_X86_64_NONE => continue,
+                Rela::R_X86_64_64 => {
+                    let symbol = self.dynamic_symbol(&dynamic, rela.symbol_index())?;
+                    symbol
+                        .st_value
+                        .wrapping_add(bias)
+                        .wrapping_add(rela.r_addend as u64)
+                }
+                Rela::R_X86_64_RELATIVE => bias.wrapping_add(rela.r_addend as u64),
+                ty => return Err(ElfError::UnsupportedRelocation(ty)),
+            };
+            let out_of_bounds = match rela.r_offset.checked_add(8) {
+                Some(end) => rela.r_offset < image_start || end > image_end,
+                None => true,
+            };
+            if out_of_bounds {
+                return Err(ElfError::RelocationOutOfBounds(rela.r_offset));
             }
+            let target = rela.r_offset.wrapping_add(load_bias) as *mut u64;
+            ptr::write_unaligned(target, value);
         }
-        ((last_addr - base_addr + 0xfff) / 0x1000) as usize
+        Ok(())
     }
 
     pub fn program_header_iter(&self) -> impl Iterator<Item = &ProgramHeader> {
         self.program_headers.iter()
     }
 
-    pub fn entry(&self) -> u64 {
-        self.elf_header.e_entry
+    //base_addrに読み込んだ場合のエントリポイント
+    pub fn entry(&self, base_addr: u64) -> u64 {
+        self.elf_header
+            .e_entry
+            .wrapping_add(self.load_bias(base_addr))
+    }
+
+    pub fn section_header_iter(&self) -> impl Iterator<Item = &SectionHeader> {
+        self.section_headers.iter()
+    }
+
+    //.shstrtabからセクション名を引く
+    pub fn section_name(&self, section_header: &SectionHeader) -> Option<&'a str> {
+        let names = self.section_data(
+            self.section_headers
+                .get(self.elf_header.e_shstrndx as usize)?,
+        );
+        string_at(names, section_header.sh_name)
+    }
+
+    //セクションの中身(SHT_NOBITSなら空)
+    pub fn section_data(&self, section_header: &SectionHeader) -> &'a [u8] {
+        if section_header.sh_type == SectionHeader::TYPE_NOBITS {
+            return &[];
+        }
+        //範囲はnewで確認済み
+        let start = section_header.sh_offset as usize;
+        &self.buffer[start..start + section_header.sh_size as usize]
+    }
+
+    //.symtabとそれにリンクされた.strtab
+    pub fn symbol_table(&self) -> Option<SymbolTable<'a>> {
+        let symtab = self
+            .section_headers
+            .iter()
+            .find(|s| s.sh_type == SectionHeader::TYPE_SYMTAB)?;
+        let strtab = self.section_headers.get(symtab.sh_link as usize)?;
+        if strtab.sh_type != SectionHeader::TYPE_STRTAB {
+            return None;
+        }
+        Some(SymbolTable {
+            symbols: self.section_data(symtab),
+            strings: self.section_data(strtab),
+        })
+    }
+
+    pub fn load_bias(&self, base_addr: u64) -> u64 {
+        base_addr.wrapping_sub(self.calculate_base_addr())
+    }
+
+    //仮想アドレスをファイル内のオフセットに変換
+    fn file_offset(&self, vaddr: u64) -> Option<u64> {
+        self.program_headers
+            .iter()
+            .filter(|p| p.type_is_load())
+            .find(|p| vaddr >= p.p_vaddr && vaddr < p.p_vaddr + p.p_filesz)
+            .map(|p| p.p_offset + (vaddr - p.p_vaddr))
+    }
+
+    fn dynamic_info(&self) -> Result<Option<DynamicInfo>, ElfError> {
+        let program_header = match self.program_headers.iter().find(|p| p.type_is_dynamic()) {
+            Some(program_header) => program_header,
+            None => return Ok(None),
+        };
+        let mut info = DynamicInfo::default();
+        let count = program_header.p_filesz / mem::size_of::<DynamicEntry>() as u64;
+        for i in 0..count {
+            let offset = i
+                .checked_mul(mem::size_of::<DynamicEntry>() as u64)
+                .and_then(|offset| offset.checked_add(program_header.p_offset))
+                .ok_or(ElfError::InvalidDynamicSection)?;
+            let entry =
+                read::<DynamicEntry>(self.buffer, offset).ok_or(ElfError::InvalidDynamicSection)?;
+            match entry.d_tag {
+                DynamicEntry::DT_NULL => break,
+                DynamicEntry::DT_RELA => info.rela = entry.d_val,
+                DynamicEntry::DT_RELASZ => info.rela_size = entry.d_val,
+                DynamicEntry::DT_RELAENT => info.rela_entry_size = entry.d_val,
+                DynamicEntry::DT_SYMTAB => info.symtab = entry.d_val,
+                DynamicEntry::DT_SYMENT => info.symbol_entry_size = entry.d_val,
+                _ => (),
+            }
+        }
+        Ok(Some(info))
+    }
+
+    fn dynamic_symbol(&self, dynamic: &DynamicInfo, index: u32) -> Result<Symbol, ElfError> {
+        if dynamic.symtab == 0 || dynamic.symbol_entry_size < mem::size_of::<Symbol>() as u64 {
+            return Err(ElfError::InvalidDynamicSection);
+        }
+        let symtab_offset = self
+            .file_offset(dynamic.symtab)
+            .ok_or(ElfError::InvalidDynamicSection)?;
+        let offset = (index as u64)
+            .checked_mul(dynamic.symbol_entry_size)
+            .and_then(|offset| offset.checked_add(symtab_offset))
+            .ok_or(ElfError::InvalidDynamicSection)?;
+        let symbol = read::<Symbol>(self.buffer, offset).ok_or(ElfError::InvalidDynamicSection)?;
+        if symbol.st_shndx == Symbol::SHN_UNDEF {
+            return Err(ElfError::UndefinedSymbol(index));
+        }
+        Ok(symbol)
+    }
+}
+
+pub struct SymbolTable<'a> {
+    symbols: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> SymbolTable<'a> {
+    //Elf64_Symの配列そのもの
+    pub fn symbols(&self) -> &'a [u8] {
+        self.symbols
+    }
+
+    pub fn strings(&self) -> &'a [u8] {
+        self.strings
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Symbol> + 'a {
+        let symbols = self.symbols;
+        (0..symbols.len() / mem::size_of::<Symbol>())
+            .filter_map(move |i| read::<Symbol>(symbols, (i * mem::size_of::<Symbol>()) as u64))
+    }
+
+    pub fn name(&self, symbol: &Symbol) -> Option<&'a str> {
+        string_at(self.strings, symbol.st_name)
+    }
+}
+
+//PT_LOADのセグメントが収まるページ数、溢れるならNone
+//PT_LOADがなければ0
+fn page_count(program_headers: &[ProgramHeader]) -> Option<usize> {
+    let loads = program_headers.iter().filter(|p| p.type_is_load());
+    let base_addr = match loads.clone().map(|p| p.p_vaddr).min() {
+        Some(addr) => addr & !0xfff,
+        None => return Some(0),
+    };
+    let mut last_addr = 0;
+    for program_header in loads {
+        last_addr = last_addr.max(program_header.p_vaddr.checked_add(program_header.p_memsz)?);
+    }
+    //切り上げた終わりも溢れないので base_addr + ページ数 * 0x1000 は計算できる
+    let end_addr = last_addr.checked_add(0xfff)? & !0xfff;
+    Some(((end_addr - base_addr) / 0x1000) as usize)
+}
+
+//文字列テーブルのoffsetから始まるNUL終端文字列
+fn string_at(strings: &[u8], offset: u32) -> Option<&str> {
+    let bytes = strings.get(offset as usize..)?;
+    let len = bytes.iter().position(|&b| b == 0)?;
+    core::str::from_utf8(&bytes[..len]).ok()
+}
+
+//バッファの範囲を確認してからoffsetの位置の構造体を読む
+//アラインメントは保証されないのでread_unaligned
+fn read<T: Copy>(buffer: &[u8], offset: u64) -> Option<T> {
+    let end = offset.checked_add(mem::size_of::<T>() as u64)?;
+    if end > buffer.len() as u64 {
+        return None;
     }
+    Some(unsafe { ptr::read_unaligned(buffer[offset as usize..].as_ptr() as *const T) })
 }
 
 #[derive(Copy, Clone)]
@@ -86,9 +455,39 @@ struct ElfHeader {
 }
 
 impl ElfHeader {
-    fn new(buffer: &mut [u8]) -> ElfHeader {
-        let elf_header_ptr = buffer.as_ptr() as *const ElfHeader;
-        unsafe { *elf_header_ptr }
+    const MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+    const CLASS_64: u8 = 2;
+    const DATA_LITTLE_ENDIAN: u8 = 1;
+    const MACHINE_X86_64: u16 = 62;
+    const TYPE_EXEC: u16 = 2;
+    const TYPE_DYN: u16 = 3;
+
+    fn new(buffer: &[u8]) -> Result<ElfHeader, ElfError> {
+        if buffer.len() < mem::size_of::<ElfHeader>() {
+            return Err(ElfError::TooSmall { size: buffer.len() });
+        }
+        //サイズは確認済み
+        let elf_header = read::<ElfHeader>(buffer, 0).unwrap();
+
+        if elf_header.e_ident[0..4] != ElfHeader::MAGIC {
+            return Err(ElfError::InvalidMagic);
+        }
+        if elf_header.e_ident[4] != ElfHeader::CLASS_64 {
+            return Err(ElfError::UnsupportedClass(elf_header.e_ident[4]));
+        }
+        if elf_header.e_ident[5] != ElfHeader::DATA_LITTLE_ENDIAN {
+            return Err(ElfError::UnsupportedEndian(elf_header.e_ident[5]));
+        }
+        if elf_header.e_machine != ElfHeader::MACHINE_X86_64 {
+            return Err(ElfError::UnsupportedMachine(elf_header.e_machine));
+        }
+        if elf_header.e_type != ElfHeader::TYPE_EXEC && elf_header.e_type != ElfHeader::TYPE_DYN {
+            return Err(ElfError::UnsupportedType(elf_header.e_type));
+        }
+        if (elf_header.e_phentsize as usize) < mem::size_of::<ProgramHeader>() {
+            return Err(ElfError::InvalidProgramHeaderSize(elf_header.e_phentsize));
+        }
+        Ok(elf_header)
     }
 }
 
@@ -106,8 +505,25 @@ pub struct ProgramHeader {
 }
 
 impl ProgramHeader {
+    const TYPE_LOAD: u32 = 1;
+    const TYPE_DYNAMIC: u32 = 2;
+    const FLAG_EXECUTE: u32 = 1;
+    const FLAG_WRITE: u32 = 2;
+
     pub fn type_is_load(&self) -> bool {
-        self.p_type == 1
+        self.p_type == ProgramHeader::TYPE_LOAD
+    }
+
+    pub fn type_is_dynamic(&self) -> bool {
+        self.p_type == ProgramHeader::TYPE_DYNAMIC
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.p_flags & ProgramHeader::FLAG_EXECUTE != 0
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.p_flags & ProgramHeader::FLAG_WRITE != 0
     }
 
     pub fn p_vaddr(&self) -> u64 {
@@ -118,7 +534,167 @@ impl ProgramHeader {
         self.p_offset
     }
 
+    pub fn p_filesz(&self) -> u64 {
+        self.p_filesz
+    }
+
     pub fn p_memsz(&self) -> u64 {
         self.p_memsz
     }
+
+    pub fn p_flags(&self) -> u32 {
+        self.p_flags
+    }
+
+    pub fn p_align(&self) -> u64 {
+        self.p_align
+    }
+
+    fn validate(&self, file_size: usize) -> Result<(), ElfError> {
+        if self.p_filesz > self.p_memsz {
+            return Err(ElfError::InvalidSegmentSize {
+                p_filesz: self.p_filesz,
+                p_memsz: self.p_memsz,
+            });
+        }
+        if self.p_vaddr.checked_add(self.p_memsz).is_none() {
+            return Err(ElfError::SegmentAddressOverflow {
+                p_vaddr: self.p_vaddr,
+                p_memsz: self.p_memsz,
+            });
+        }
+        let out_of_bounds = match self.p_offset.checked_add(self.p_filesz) {
+            Some(end) => end > file_size as u64,
+            None => true,
+        };
+        if out_of_bounds {
+            return Err(ElfError::SegmentOutOfBounds {
+                p_offset: self.p_offset,
+                p_filesz: self.p_filesz,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct DynamicEntry {
+    d_tag: i64,
+    d_val: u64,
+}
+
+impl DynamicEntry {
+    const DT_NULL: i64 = 0;
+    const DT_SYMTAB: i64 = 6;
+    const DT_RELA: i64 = 7;
+    const DT_RELASZ: i64 = 8;
+    const DT_RELAENT: i64 = 9;
+    const DT_SYMENT: i64 = 11;
+}
+
+#[derive(Default)]
+struct DynamicInfo {
+    rela: u64,
+    rela_size: u64,
+    rela_entry_size: u64,
+    symtab: u64,
+    symbol_entry_size: u64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct Rela {
+    r_offset: u64,
+    r_info: u64,
+    r_addend: i64,
+}
+
+impl Rela {
+    const R_X86_64_NONE: u32 = 0;
+    const R_X86_64_64: u32 = 1;
+    const R_X86_64_RELATIVE: u32 = 8;
+
+    fn relocation_type(&self) -> u32 {
+        (self.r_info & 0xffff_ffff) as u32
+    }
+
+    fn symbol_index(&self) -> u32 {
+        (self.r_info >> 32) as u32
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SectionHeader {
+    sh_name: u32,
+    sh_type: u32,
+    sh_flags: u64,
+    sh_addr: u64,
+    sh_offset: u64,
+    sh_size: u64,
+    sh_link: u32,
+    sh_info: u32,
+    sh_addralign: u64,
+    sh_entsize: u64,
+}
+
+impl SectionHeader {
+    const TYPE_SYMTAB: u32 = 2;
+    const TYPE_STRTAB: u32 = 3;
+    const TYPE_NOBITS: u32 = 8;
+
+    pub fn sh_type(&self) -> u32 {
+        self.sh_type
+    }
+
+    pub fn sh_addr(&self) -> u64 {
+        self.sh_addr
+    }
+
+    pub fn sh_offset(&self) -> u64 {
+        self.sh_offset
+    }
+
+    pub fn sh_size(&self) -> u64 {
+        self.sh_size
+    }
+
+    fn is_within(&self, file_size: usize) -> bool {
+        if self.sh_type == SectionHeader::TYPE_NOBITS {
+            return true;
+        }
+        match self.sh_offset.checked_add(self.sh_size) {
+            Some(end) => end <= file_size as u64,
+            None => false,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Symbol {
+    st_name: u32,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+    st_value: u64,
+    st_size: u64,
+}
+
+impl Symbol {
+    const SHN_UNDEF: u16 = 0;
+    const TYPE_FUNC: u8 = 2;
+
+    pub fn st_value(&self) -> u64 {
+        self.st_value
+    }
+
+    pub fn st_size(&self) -> u64 {
+        self.st_size
+    }
+
+    pub fn is_function(&self) -> bool {
+        self.st_info & 0xf == Symbol::TYPE_FUNC
+    }
 }
@@ -0,0 +1,137 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use uefi::prelude::*;
+use uefi::proto::media::file::{
+    Directory, File, FileAttribute, FileInfo, FileMode, FileSystemInfo, RegularFile,
+};
+
+//FileInfoはファイル名を含むので最初はファイル名が短いと仮定する
+const FILE_INFO_BUFFER_SIZE: usize = 128;
+
+//ファイル全体を読み込む
+//メモリはグローバルアロケータ(LOADER_DATAのプール)から確保する
+pub fn read_file(root_dir: &mut Directory, path: &str) -> Result<Box<[u8]>, Status> {
+    let file_handle = root_dir
+        .open(path, FileMode::Read, FileAttribute::empty())
+        .log_warning()
+        .map_err(|e| e.status())?;
+    //安全性は不明
+    let mut file = unsafe { RegularFile::new(file_handle) };
+    let size = file_size(&mut file)?;
+
+    let mut data = vec![0; size].into_boxed_slice();
+    let mut position = 0;
+    while position < size {
+        let read_size = file
+            .read(&mut data[position..])
+            .log_warning()
+            .map_err(|e| e.status())?;
+        //途中でファイルが短くなった
+        if read_size == 0 {
+            return Err(Status::END_OF_FILE);
+        }
+        position += read_size;
+    }
+    Ok(data)
+}
+
+//開けるかどうかだけ確かめる
+pub fn exists(dir: &mut Directory, path: &str) -> bool {
+    dir.open(path, FileMode::Read, FileAttribute::empty())
+        .log_warning()
+        .is_ok()
+}
+
+//ファイル全体を書き換える
+pub fn write_file(root_dir: &mut Directory, path: &str, data: &[u8]) -> Result<(), Status> {
+    //CreateReadWriteは切り詰めないので前の内容が長いと後ろが残る、先に消しておく
+    if let Ok(file_handle) = root_dir
+        .open(path, FileMode::ReadWrite, FileAttribute::empty())
+        .log_warning()
+    {
+        //安全性は不明
+        unsafe { RegularFile::new(file_handle) }
+            .delete()
+            .log_warning()
+            .map_err(|e| e.status())?;
+    }
+    let file_handle = root_dir
+        .open(path, FileMode::CreateReadWrite, FileAttribute::empty())
+        .log_warning()
+        .map_err(|e| e.status())?;
+    //安全性は不明
+    let mut file = unsafe { RegularFile::new(file_handle) };
+    file.write(data).log_warning().map_err(|e| e.status())?;
+    //自分の環境ではこれを書かないと変更が反映されなかった
+    file.flush().log_warning().map_err(|e| e.status())
+}
+
+//バッファが足りなければ必要な大きさが返ってくるので確保し直す
+pub fn file_size(file: &mut RegularFile) -> Result<usize, Status> {
+    //AllocatePoolは8バイト境界なのでFileInfoのアラインメントを満たす
+    let mut buffer = vec![0; FILE_INFO_BUFFER_SIZE];
+    loop {
+        match file.get_info::<FileInfo>(&mut buffer).log_warning() {
+            Ok(file_info) => return Ok(file_info.file_size() as usize),
+            Err(e) if e.status() == Status::BUFFER_TOO_SMALL => {
+                let required_size = e.data().unwrap_or(buffer.len() * 2);
+                buffer = vec![0; required_size];
+            }
+            Err(e) => return Err(e.status()),
+        }
+    }
+}
+
+//ディレクトリ直下のファイル名、ディレクトリは含まない
+pub fn file_names(dir: &mut Directory) -> Result<Vec<String>, Status> {
+    let mut names = Vec::new();
+    //AllocatePoolは8バイト境界なのでFileInfoのアラインメントを満たす
+    let mut buffer = vec![0; FILE_INFO_BUFFER_SIZE];
+    loop {
+        match dir.read_entry(&mut buffer).log_warning() {
+            Ok(Some(file_info)) => {
+                if file_info.attribute().contains(FileAttribute::DIRECTORY) {
+                    continue;
+                }
+                names.push(from_ucs2(file_info.file_name().to_u16_slice()));
+            }
+            Ok(None) => return Ok(names),
+            Err(e) if e.status() == Status::BUFFER_TOO_SMALL => {
+                let required_size = e.data().unwrap_or(buffer.len() * 2);
+                buffer = vec![0; required_size];
+            }
+            Err(e) => return Err(e.status()),
+        }
+    }
+}
+
+//ボリュームのラベル、付いていなければ空文字列
+pub fn volume_label(root_dir: &mut Directory) -> Result<String, Status> {
+    //AllocatePoolは8バイト境界なのでFileSystemInfoのアラインメントを満たす
+    let mut buffer = vec![0; FILE_INFO_BUFFER_SIZE];
+    loop {
+        match root_dir
+            .get_info::<FileSystemInfo>(&mut buffer)
+            .log_warning()
+        {
+            Ok(file_system_info) => {
+                return Ok(from_ucs2(file_system_info.volume_label().to_u16_slice()))
+            }
+            Err(e) if e.status() == Status::BUFFER_TOO_SMALL => {
+                let required_size = e.data().unwrap_or(buffer.len() * 2);
+                buffer = vec![0; required_size];
+            }
+            Err(e) => return Err(e.status()),
+        }
+    }
+}
+
+fn from_ucs2(name: &[u16]) -> String {
+    char::decode_utf16(name.iter().cloned())
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
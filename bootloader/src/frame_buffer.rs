@@ -0,0 +1,94 @@
+use crate::arg::{FrameBuffer, FrameBufferConfig, PixelFormat};
+use core::fmt;
+use core::ptr;
+
+//exit_boot_services後に文字を書くための最低限のコンソール
+//黒地に白で書き、最後の行まで来たら先頭の行に戻る
+pub struct FrameBufferWriter {
+    frame_buffer: FrameBuffer,
+    config: FrameBufferConfig,
+    column: usize,
+    row: usize,
+}
+
+impl FrameBufferWriter {
+    pub fn new(frame_buffer: FrameBuffer, config: FrameBufferConfig) -> FrameBufferWriter {
+        FrameBufferWriter {
+            frame_buffer,
+            config,
+            column: 0,
+            row: 0,
+        }
+    }
+
+    fn columns(&self) -> usize {
+        self.config.horizontal_resolution / shinonome::WIDTH
+    }
+
+    fn rows(&self) -> usize {
+        self.config.vertical_resolution / shinonome::HEIGHT
+    }
+
+    fn white(&self) -> u32 {
+        match self.config.pixel_format {
+            PixelFormat::Rgb | PixelFormat::Bgr => 0x00ff_ffff,
+            PixelFormat::Bitmask { red, green, blue } => red | green | blue,
+        }
+    }
+
+    fn write_pixel(&self, x: usize, y: usize, value: u32) {
+        let offset = 4 * (self.config.pixels_per_scan_line * y + x);
+        if offset + 4 > self.frame_buffer.size {
+            return;
+        }
+        //範囲は確認済み
+        unsafe {
+            ptr::write_volatile(self.frame_buffer.base.add(offset) as *mut u32, value);
+        }
+    }
+
+    fn write_char(&mut self, c: char) {
+        if c == '\n' {
+            self.new_line();
+            return;
+        }
+        if self.column >= self.columns() {
+            self.new_line();
+        }
+        let white = self.white();
+        //ASCII以外は'?'にする
+        let glyph = shinonome::glyph(c).unwrap_or(&shinonome::GLYPHS[b'?' as usize]);
+        let (left, top) = (self.column * shinonome::WIDTH, self.row * shinonome::HEIGHT);
+        for (dy, line) in glyph.iter().enumerate() {
+            for dx in 0..shinonome::WIDTH {
+                let value = if (line << dx) & 0x80 != 0 { white } else { 0 };
+                self.write_pixel(left + dx, top + dy, value);
+            }
+        }
+        self.column += 1;
+    }
+
+    fn new_line(&mut self) {
+        self.column = 0;
+        self.row += 1;
+        if self.row >= self.rows() {
+            self.row = 0;
+        }
+        //前の内容が残っていると読めないので行を消す
+        let top = self.row * shinonome::HEIGHT;
+        for y in top..top + shinonome::HEIGHT {
+            for x in 0..self.config.horizontal_resolution {
+                self.write_pixel(x, y, 0);
+            }
+        }
+    }
+}
+
+impl fmt::Write for FrameBufferWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c);
+        }
+        Ok(())
+    }
+}
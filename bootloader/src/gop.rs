@@ -0,0 +1,64 @@
+use crate::arg;
+use uefi::proto::console::gop::{GraphicsOutput, Mode, ModeInfo, PixelFormat};
+use uefi::ResultExt;
+
+//カーネルが描画できるピクセル形式か
+//BltOnlyはフレームバッファに直接書けない
+pub fn is_supported(pixel_format: PixelFormat) -> bool {
+    matches!(
+        pixel_format,
+        PixelFormat::Rgb | PixelFormat::Bgr | PixelFormat::Bitmask
+    )
+}
+
+//カーネルに渡す形式に変換する、描画できなければNone
+pub fn to_arg_pixel_format(info: &ModeInfo) -> Option<arg::PixelFormat> {
+    match info.pixel_format() {
+        PixelFormat::Rgb => Some(arg::PixelFormat::Rgb),
+        PixelFormat::Bgr => Some(arg::PixelFormat::Bgr),
+        PixelFormat::Bitmask => {
+            let mask = info.pixel_bitmask()?;
+            Some(arg::PixelFormat::Bitmask {
+                red: mask.red,
+                green: mask.green,
+                blue: mask.blue,
+            })
+        }
+        PixelFormat::BltOnly => None,
+    }
+}
+
+//使うモードを選ぶ
+//解像度の指定が無いか、指定した解像度が無ければ一番大きいモードにする
+pub fn select_mode(
+    gop: &GraphicsOutput,
+    resolution: Option<(usize, usize)>,
+    pixel_format: Option<PixelFormat>,
+) -> Option<Mode> {
+    let is_candidate = |mode: &Mode| {
+        let info = mode.info();
+        let pixel_format_matches = match pixel_format {
+            Some(pixel_format) => info.pixel_format() == pixel_format,
+            None => true,
+        };
+        is_supported(info.pixel_format()) && pixel_format_matches
+    };
+
+    if let Some(resolution) = resolution {
+        let mode = gop
+            .modes()
+            .map(|mode| mode.log())
+            .filter(|mode| is_candidate(mode))
+            .find(|mode| mode.info().resolution() == resolution);
+        if mode.is_some() {
+            return mode;
+        }
+    }
+    gop.modes()
+        .map(|mode| mode.log())
+        .filter(|mode| is_candidate(mode))
+        .max_by_key(|mode| {
+            let (horizontal, vertical) = mode.info().resolution();
+            horizontal * vertical
+        })
+}
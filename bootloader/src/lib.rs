@@ -1,5 +1,19 @@
 #![no_std]
+#![feature(asm)]
 
-pub mod arg;
+pub use arg;
+pub mod bootlog;
+pub mod config;
+pub mod decompress;
 pub mod elf;
-pub mod vga;
+pub mod file;
+pub mod frame_buffer;
+pub mod gop;
+pub mod memory_map;
+pub mod menu;
+pub mod paging;
+pub mod panic;
+pub mod serial;
+pub mod sha256;
+#[cfg(feature = "verified-boot")]
+pub mod signature;
@@ -1,22 +1,45 @@
 #![feature(abi_efiapi)]
 #![feature(alloc_error_handler)]
+#![feature(asm)]
 #![no_std]
 #![no_main]
 
 extern crate alloc;
 
+use alloc::string::String;
+use alloc::vec::Vec;
 use bootloader::arg;
+use bootloader::bootlog::BootLog;
+use bootloader::config::Config;
+use bootloader::decompress;
 use bootloader::elf::Elf;
-use bootloader::vga::Writer;
+use bootloader::file;
+use bootloader::gop;
+use bootloader::memory_map;
+use bootloader::menu;
+use bootloader::paging::{self, PageFlags, PageTableBuilder, PagingError};
+use bootloader::panic;
+use bootloader::sha256;
+#[cfg(feature = "verified-boot")]
+use bootloader::signature;
 use core::alloc::Layout;
+use core::ffi::c_void;
+use core::fmt;
 use core::fmt::Write;
 use core::mem;
 use core::panic::PanicInfo;
+use core::ptr;
 use core::slice;
+use core::str;
 use uefi::prelude::*;
-use uefi::proto::console::gop::{GraphicsOutput, PixelFormat};
-use uefi::proto::media::file::{File, FileAttribute, FileInfo, FileMode, RegularFile};
-use uefi::table::boot::{AllocateType, MemoryAttribute, MemoryType};
+use uefi::proto::console::gop::GraphicsOutput;
+use uefi::proto::console::text::{Input, Output};
+use uefi::proto::loaded_image::LoadedImage;
+use uefi::proto::media::file::Directory;
+use uefi::proto::media::fs::SimpleFileSystem;
+use uefi::table::boot::{AllocateType, MemoryDescriptor, MemoryType};
+use uefi::table::cfg;
+use uefi::table::Runtime;
 
 #[alloc_error_handler]
 fn on_oom(_layout: Layout) -> ! {
@@ -24,17 +47,31 @@ fn on_oom(_layout: Layout) -> ! {
 }
 
 #[panic_handler]
-fn panic(info: &PanicInfo) -> ! {
-    //vga(https://github.com/phil-opp/blog_os/blob/post-03/src/vga_buffer.rs)
-    let mut writer = Writer::new();
-    writeln!(writer, "{}", info).unwrap();
-    loop {}
+fn panic_handler(info: &PanicInfo) -> ! {
+    panic::write(info);
+    loop {
+        unsafe { asm!("hlt") };
+    }
 }
 
+//Argumentのバッファに収まるように数を制限する
+const MAX_MODULES: usize = 16;
+
+//Argumentを書き込むバッファのu64の数
+const ARGS_BUFFER_SIZE: usize = 512;
+
+//メモリマップのバッファに余分にとる記述子の数
+//バッファを確保すること自体で記述子が増えることがある
+const MEMORY_MAP_SLACK: usize = 8;
+
 #[entry]
 fn efi_main(handle: Handle, system_table: SystemTable<Boot>) -> Status {
     let boot_services = system_table.boot_services();
     let stdout = system_table.stdout();
+    //安全性: exit_boot_servicesの後はuse_frame_bufferで切り替える
+    unsafe {
+        panic::use_uefi_stdout(stdout);
+    }
 
     //uefi-rsのextsフィーチャー(feature = exts)を使うために初期化が必要
     //extsフィーチャーを使わないならいらない
@@ -42,10 +79,13 @@ fn efi_main(handle: Handle, system_table: SystemTable<Boot>) -> Status {
         uefi::alloc::init(boot_services);
     }
 
-    writeln!(stdout, "Hello, world!").unwrap();
+    //アロケータを初期化した後でないと作れない
+    let mut boot_log = BootLog::new(stdout);
+    writeln!(boot_log, "Hello, world!").unwrap();
 
-    //バッファサイズは適当
-    let memory_map_buffer = &mut [0; 4096 * 4];
+    //記述子の数は環境によって違うので必要な大きさを問い合わせる
+    let memory_map_buffer =
+        &mut alloc::vec![0; memory_map_buffer_size(boot_services, MEMORY_MAP_SLACK)];
     //このResultはuefi-rs独自実装のためunwrap_successを使う。
     let (_memory_map_key, descriptor_iter) =
         boot_services.memory_map(memory_map_buffer).unwrap_success();
@@ -59,69 +99,92 @@ fn efi_main(handle: Handle, system_table: SystemTable<Boot>) -> Status {
     //安全性はget_image_file_systemに依存
     let mut root_dir = unsafe { (*file_system).open_volume().unwrap_success() };
 
-    let memory_map_file_handle = root_dir
-        .open(
-            "\\memmap",
-            FileMode::CreateReadWrite,
-            FileAttribute::empty(),
+    let status = boot(
+        handle,
+        &system_table,
+        &mut root_dir,
+        &mut boot_log,
+        descriptor_iter,
+    );
+    //起動できなかったときこそログが要るのでここでも残す
+    if let Err(status) = boot_log.flush(&mut root_dir) {
+        writeln!(
+            boot_log.stdout(),
+            "Failed to write {}: {:?}",
+            BootLog::PATH,
+            status
         )
-        .unwrap_success();
-    //RegularFileに変換する必要あり(unsafe)
-    //理由はよくわかっていない
-    //安全性もよくわからない
-    let mut memory_map_file = unsafe { RegularFile::new(memory_map_file_handle) };
+        .unwrap();
+    }
+    status
+}
 
-    memory_map_file
-        .write("Type, PhysicalStart, NumberOfPages, Attribute\n".as_bytes())
-        .unwrap_success();
-    for descriptor in descriptor_iter {
-        let memory_type: u32 = match descriptor.ty {
-            MemoryType::RESERVED => 0,
-            MemoryType::LOADER_CODE => 1,
-            MemoryType::LOADER_DATA => 2,
-            MemoryType::BOOT_SERVICES_CODE => 3,
-            MemoryType::BOOT_SERVICES_DATA => 4,
-            MemoryType::RUNTIME_SERVICES_CODE => 5,
-            MemoryType::RUNTIME_SERVICES_DATA => 6,
-            MemoryType::CONVENTIONAL => 7,
-            MemoryType::UNUSABLE => 8,
-            MemoryType::ACPI_RECLAIM => 9,
-            MemoryType::ACPI_NON_VOLATILE => 10,
-            MemoryType::MMIO => 11,
-            MemoryType::MMIO_PORT_SPACE => 12,
-            MemoryType::PAL_CODE => 13,
-            MemoryType::PERSISTENT_MEMORY => 14,
-            _ => 0xffff_ffff,
-        };
-        let physical_start = descriptor.phys_start;
-        let number_of_pages = descriptor.page_count;
-        let attribute: u64 = match descriptor.att {
-            MemoryAttribute::UNCACHEABLE => 0x1,
-            MemoryAttribute::WRITE_COMBINE => 0x2,
-            MemoryAttribute::WRITE_THROUGH => 0x4,
-            MemoryAttribute::WRITE_BACK => 0x8,
-            MemoryAttribute::UNCACHABLE_EXPORTED => 0x10,
-            MemoryAttribute::WRITE_PROTECT => 0x1000,
-            MemoryAttribute::READ_PROTECT => 0x2000,
-            MemoryAttribute::EXECUTE_PROTECT => 0x4000,
-            MemoryAttribute::NON_VOLATILE => 0x8000,
-            MemoryAttribute::MORE_RELIABLE => 0x10000,
-            MemoryAttribute::READ_ONLY => 0x20000,
-            MemoryAttribute::RUNTIME => 0x8000_0000_0000_0000,
-            _ => 0,
-        };
-
-        let line = alloc::format!(
-            "{:016x},{:016x},{:016x},{:016x}\n",
-            memory_type,
-            physical_start,
-            number_of_pages,
-            attribute
+//カーネルを読み込んで起動する、exit_boot_servicesまでに失敗したらそのStatusを返す
+fn boot<'a>(
+    handle: Handle,
+    system_table: &SystemTable<Boot>,
+    root_dir: &mut Directory,
+    log: &mut BootLog,
+    descriptor_iter: impl Iterator<Item = &'a MemoryDescriptor>,
+) -> Status {
+    let boot_services = system_table.boot_services();
+    let config = load_config(root_dir, log);
+    log.phase("config");
+
+    if config.dump_memory_map {
+        dump_memory_map(root_dir, log, descriptor_iter);
+        log.phase("memory map");
+    }
+
+    //カーネルが複数あればメニューで選ぶ
+    let kernel_entries = if config.entries.is_empty() {
+        find_kernels(root_dir)
+    } else {
+        config.entries.clone()
+    };
+    let show_menu = config.timeout > 0 && kernel_entries.len() > 1;
+    let kernel_path = if show_menu {
+        let default = kernel_entries
+            .iter()
+            .position(|entry| entry.eq_ignore_ascii_case(&config.kernel_path))
+            .unwrap_or(0);
+        let selected = menu::select(
+            boot_services,
+            log.stdout(),
+            system_table.stdin(),
+            &kernel_entries,
+            default,
+            config.timeout,
         );
-        memory_map_file.write(line.as_bytes()).unwrap_success();
+        log.phase("menu");
+        kernel_entries[selected].clone()
+    } else {
+        config.kernel_path.clone()
+    };
+
+    //起動したボリュームに無ければ2台目のディスクなど他のボリュームから探す
+    //設定ファイルとログは起動したボリュームのものを使う
+    let mut other_volume = None;
+    if file::exists(root_dir, &kernel_path) {
+        writeln!(log, "Kernel volume: boot volume").unwrap();
+    } else {
+        match find_volume(boot_services, &kernel_path) {
+            Some((index, mut volume)) => {
+                let label = file::volume_label(&mut volume).unwrap_or_default();
+                writeln!(log, "Kernel volume: volume {} \"{}\"", index, label).unwrap();
+                other_volume = Some(volume);
+            }
+            None => {
+                writeln!(log, "{} not found on any volume", kernel_path).unwrap();
+                return Status::NOT_FOUND;
+            }
+        }
     }
-    //自分の環境ではこれを書かないと変更が反映されなかった
-    memory_map_file.flush().unwrap_success();
+    let kernel_dir = match &mut other_volume {
+        Some(volume) => volume,
+        None => &mut *root_dir,
+    };
+    log.phase("find kernel");
 
     //feature = exts
     let gop_handles = boot_services
@@ -133,6 +196,42 @@ fn efi_main(handle: Handle, system_table: SystemTable<Boot>) -> Status {
         .unwrap_success()
         .get();
     //安全性はhandle_protocolに依存
+    match gop::select_mode(unsafe { &*gop }, config.resolution, config.pixel_format) {
+        Some(mode) => {
+            let (horizontal_resolution, vertical_resolution) = mode.info().resolution();
+            if config.resolution.is_some()
+                && config.resolution != Some((horizontal_resolution, vertical_resolution))
+            {
+                writeln!(log, "Preferred resolution is not supported").unwrap();
+            }
+            writeln!(
+                log,
+                "Video mode: {}x{} {:?}",
+                horizontal_resolution,
+                vertical_resolution,
+                mode.info().pixel_format()
+            )
+            .unwrap();
+            unsafe { (*gop).set_mode(&mode).unwrap_success() };
+        }
+        None => writeln!(log, "No usable video mode, keeping the current one").unwrap(),
+    }
+    //ここまで動いてるなら安全
+    let gop_mode_info = unsafe { (*gop).current_mode_info() };
+    //exit_boot_servicesの後ではエラーを表示できないのでここで確かめる
+    let pixel_format = match gop::to_arg_pixel_format(&gop_mode_info) {
+        Some(pixel_format) => pixel_format,
+        None => {
+            writeln!(
+                log,
+                "Unsupported pixel format {:?}: no linear frame buffer to pass to the kernel",
+                gop_mode_info.pixel_format()
+            )
+            .unwrap();
+            return Status::UNSUPPORTED;
+        }
+    };
+    //安全性はhandle_protocolに依存
     let mut frame_buffer = unsafe { (*gop).frame_buffer() };
     for i in 0..frame_buffer.size() {
         //安全性は不明
@@ -140,93 +239,928 @@ fn efi_main(handle: Handle, system_table: SystemTable<Boot>) -> Status {
             frame_buffer.write_byte(i, 255);
         }
     }
+    log.phase("video mode");
 
-    let kernel_file_handle = root_dir
-        .open("\\kernel.elf", FileMode::Read, FileAttribute::empty())
-        .unwrap_success();
-    //安全性は不明
-    let mut kernel_file = unsafe { RegularFile::new(kernel_file_handle) };
-    //kernel_file_info_bufferのサイズ=構造体のサイズ+ファイル名(kernel.elf=12*16bit)
-    let kernel_file_info_buffer = &mut [0; 80 + 24];
-    let kernel_file_info: &mut FileInfo = kernel_file
-        .get_info(kernel_file_info_buffer)
-        .unwrap()
-        .unwrap();
-    let kernel_file_size = kernel_file_info.file_size();
-    let kernel_file_buffer_ptr = boot_services
-        .allocate_pool(MemoryType::LOADER_DATA, kernel_file_size as usize)
-        .unwrap_success();
-    //安全性はallocate_poolに依存
-    let kernel_file_buffer =
-        unsafe { slice::from_raw_parts_mut(kernel_file_buffer_ptr, kernel_file_size as usize) };
-    kernel_file.read(kernel_file_buffer).unwrap_success();
-
-    let elf_file = Elf::new(kernel_file_buffer);
-    let kernel_base_addr = elf_file.calculate_base_addr() as usize;
-    let kernel_page_count = elf_file.calculate_page_count();
-    boot_services
-        .allocate_pages(
-            AllocateType::Address(kernel_base_addr),
-            MemoryType::LOADER_DATA,
-            kernel_page_count,
-        )
-        .unwrap_success();
-    for program_header in elf_file.program_header_iter() {
-        if !program_header.type_is_load() {
-            continue;
-        }
-        let addr = program_header.p_vaddr() as *mut u8;
-        let offset = program_header.p_offset() as usize;
-        let size = program_header.p_memsz();
-        //読み込みは1バイト単位
-        //もっといい方法があるかも？
-        for i in 0..size {
-            //安全性は不明
-            unsafe {
-                boot_services.memset(
-                    addr.offset(i as isize),
-                    1,
-                    kernel_file_buffer[offset + i as usize],
-                );
-            }
-        }
-    }
-
-    writeln!(stdout, "Bye").unwrap();
-    system_table
-        .exit_boot_services(handle, memory_map_buffer)
-        .unwrap_success();
-
-    let kernel_entry_point = elf_file.entry() as *const ();
-    let kernel_entry = unsafe {
-        mem::transmute::<*const (), extern "sysv64" fn(args_ptr: *const arg::Argument) -> !>(
-            kernel_entry_point,
-        )
-    };
     let frame_buffer_base = frame_buffer.as_mut_ptr();
     let frame_buffer_size = frame_buffer.size();
     let arg_frame_buffer = arg::FrameBuffer {
         base: frame_buffer_base,
         size: frame_buffer_size,
     };
-    //ここまで動いてるなら安全
-    let gop_mode_info = unsafe { (*gop).current_mode_info() };
     let pixels_per_scan_line = gop_mode_info.stride();
     let (horizontal_resolution, vertical_resolution) = gop_mode_info.resolution();
-    let pixel_format = match gop_mode_info.pixel_format() {
-        PixelFormat::Rgb => arg::PixelFormat::Rgb,
-        PixelFormat::Bgr => arg::PixelFormat::Bgr,
-        _ => panic!("Unimplemented"),
-    };
     let arg_frame_buffer_config = arg::FrameBufferConfig {
         pixels_per_scan_line: pixels_per_scan_line,
         horizontal_resolution: horizontal_resolution,
         vertical_resolution: vertical_resolution,
         pixel_format: pixel_format,
     };
-    let args = arg::Argument {
-        frame_buffer: arg_frame_buffer,
-        frame_buffer_config: arg_frame_buffer_config,
+
+    let kernel_file_buffer = match file::read_file(kernel_dir, &kernel_path) {
+        Ok(kernel_file_buffer) => kernel_file_buffer,
+        Err(status) => {
+            writeln!(log, "Failed to read {}: {:?}", kernel_path, status).unwrap();
+            return status;
+        }
+    };
+    writeln!(
+        log,
+        "Read {} ({} bytes)",
+        kernel_path,
+        kernel_file_buffer.len()
+    )
+    .unwrap();
+    log.phase("read kernel");
+
+    //古いカーネルや途中までしかコピーされていないカーネルを起動しないようにする
+    let kernel_digest = sha256::digest(&kernel_file_buffer);
+    if let Err(message) = verify_digest(kernel_dir, &kernel_path, &kernel_digest) {
+        writeln!(log, "Refusing to boot {}: {}", kernel_path, message).unwrap();
+        return Status::SECURITY_VIOLATION;
+    }
+    writeln!(log, "Kernel SHA-256 {}", HexDigest(&kernel_digest)).unwrap();
+
+    #[cfg(feature = "verified-boot")]
+    {
+        let signature_path = alloc::format!("{}.sig", kernel_path);
+        let signature = file::read_file(kernel_dir, &signature_path).ok();
+        if let Err(e) = signature::verify(&kernel_file_buffer, signature.as_deref()) {
+            writeln!(log, "Refusing to boot {}: {}", kernel_path, e).unwrap();
+            return Status::SECURITY_VIOLATION;
+        }
+        if signature::has_public_key() {
+            writeln!(log, "Kernel signature verified").unwrap();
+        } else {
+            writeln!(log, "No public key embedded, skipping signature check").unwrap();
+        }
+    }
+
+    log.phase("verify kernel");
+
+    //ハッシュと署名はファイルそのものに対して確認し、圧縮されていればその後で展開する
+    let decompressed_kernel = match decompress::decompress(&kernel_file_buffer) {
+        Ok(decompressed_kernel) => decompressed_kernel,
+        Err(e) => {
+            writeln!(
+                log,
+                "Failed to decompress {} ({}): {}",
+                kernel_path,
+                decompress::detect(&kernel_file_buffer),
+                e
+            )
+            .unwrap();
+            return Status::LOAD_ERROR;
+        }
+    };
+    let kernel_image: &[u8] = match &decompressed_kernel {
+        Some(decompressed_kernel) => {
+            writeln!(
+                log,
+                "Decompressed {} ({}, {} -> {} bytes)",
+                kernel_path,
+                decompress::detect(&kernel_file_buffer),
+                kernel_file_buffer.len(),
+                decompressed_kernel.len()
+            )
+            .unwrap();
+            decompressed_kernel
+        }
+        None => &kernel_file_buffer,
+    };
+    log.phase("decompress kernel");
+
+    let elf_file = match Elf::new(kernel_image) {
+        Ok(elf_file) => elf_file,
+        Err(e) => {
+            writeln!(log, "Failed to load {}: {}", kernel_path, e).unwrap();
+            return Status::LOAD_ERROR;
+        }
+    };
+    //ET_DYNならどこに置いてもよいのでファームウェアに任せる
+    let allocate_type = if elf_file.is_position_independent() {
+        AllocateType::AnyPages
+    } else {
+        AllocateType::Address(elf_file.calculate_base_addr() as usize)
+    };
+    let kernel_page_count = elf_file.calculate_page_count();
+    let kernel_base_addr = match boot_services
+        .allocate_pages(allocate_type, MemoryType::LOADER_DATA, kernel_page_count)
+        .log_warning()
+    {
+        Ok(kernel_base_addr) => kernel_base_addr,
+        Err(e) => {
+            //ET_EXECはリンクしたアドレスが空いていないと失敗するので場所も出す
+            if elf_file.is_position_independent() {
+                writeln!(
+                    log,
+                    "Failed to allocate {} pages for the kernel: {:?}",
+                    kernel_page_count,
+                    e.status()
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    log,
+                    "Failed to allocate {} pages at {:#x} for the kernel: {:?}",
+                    kernel_page_count,
+                    elf_file.calculate_base_addr(),
+                    e.status()
+                )
+                .unwrap();
+            }
+            return Status::LOAD_ERROR;
+        }
+    };
+    //ET_DYNなら高位に写して動かす、そうでなければリンクしたアドレスのまま
+    let kernel_virtual_base = if elf_file.is_position_independent() {
+        paging::KERNEL_BASE
+    } else {
+        kernel_base_addr
+    };
+    //安全性はallocate_pagesに依存
+    let relocation_result = unsafe {
+        elf_file.load_segments(kernel_base_addr);
+        elf_file.apply_relocations(kernel_base_addr, kernel_virtual_base)
+    };
+    if let Err(e) = relocation_result {
+        writeln!(log, "Failed to relocate {}: {}", kernel_path, e).unwrap();
+        return Status::LOAD_ERROR;
+    }
+    writeln!(
+        log,
+        "Kernel loaded at {:#x} ({} pages), mapped at {:#x}",
+        kernel_base_addr, kernel_page_count, kernel_virtual_base
+    )
+    .unwrap();
+    //画面に出すには多いのでファイルにだけ残す
+    for program_header in elf_file
+        .program_header_iter()
+        .filter(|program_header| program_header.type_is_load())
+    {
+        writeln!(
+            log.file_only(),
+            "Segment {:#x} file {:#x} memory {:#x} {}{}",
+            program_header.p_vaddr(),
+            program_header.p_filesz(),
+            program_header.p_memsz(),
+            if program_header.is_writable() {
+                "W"
+            } else {
+                "-"
+            },
+            if program_header.is_executable() {
+                "X"
+            } else {
+                "-"
+            }
+        )
+        .unwrap();
+    }
+    log.phase("load kernel");
+
+    //パニック時のシンボル解決用にシンボルテーブルをカーネルに渡す
+    let arg_symbol_table = match elf_file.symbol_table() {
+        Some(symbol_table) => arg::SymbolTable {
+            symbols: copy_to_loader_data(boot_services, symbol_table.symbols()),
+            symbols_size: symbol_table.symbols().len(),
+            strings: copy_to_loader_data(boot_services, symbol_table.strings()),
+            strings_size: symbol_table.strings().len(),
+            load_bias: elf_file.load_bias(kernel_virtual_base),
+        },
+        None => {
+            writeln!(log, "No symbol table in {}", kernel_path).unwrap();
+            arg::SymbolTable {
+                symbols: ptr::null(),
+                symbols_size: 0,
+                strings: ptr::null(),
+                strings_size: 0,
+                load_bias: elf_file.load_bias(kernel_virtual_base),
+            }
+        }
+    };
+
+    //initrdとモジュールはカーネルと同じボリュームから読む
+    let mut modules = Vec::new();
+    if let Some(initrd_path) = &config.initrd_path {
+        match load_module(boot_services, kernel_dir, initrd_path) {
+            Ok(module) => modules.push(module),
+            //initrdは無くてもよい
+            Err(Status::NOT_FOUND) => (),
+            Err(status) => writeln!(log, "Failed to load {}: {:?}", initrd_path, status).unwrap(),
+        }
+    }
+    for module_path in &config.module_paths {
+        match load_module(boot_services, kernel_dir, module_path) {
+            Ok(module) => modules.push(module),
+            Err(status) => writeln!(log, "Failed to load {}: {:?}", module_path, status).unwrap(),
+        }
+    }
+    if modules.len() > MAX_MODULES {
+        writeln!(
+            log,
+            "Too many modules, only the first {} are passed to the kernel",
+            MAX_MODULES
+        )
+        .unwrap();
+        modules.truncate(MAX_MODULES);
+    }
+    for module in &modules {
+        //安全性はload_moduleに依存
+        let name = unsafe { slice::from_raw_parts(module.name, module.name_len) };
+        writeln!(
+            log,
+            "Module {} at {:#x} ({} bytes)",
+            str::from_utf8(name).unwrap_or("?"),
+            module.data as usize,
+            module.len
+        )
+        .unwrap();
+    }
+
+    log.phase("modules");
+
+    //APICやHPETなどのテーブルはRSDPからたどるのでカーネルに渡す
+    let rsdp = find_rsdp(system_table);
+    match rsdp {
+        Some((address, version)) => {
+            writeln!(log, "ACPI {} RSDP at {:#x}", version, address).unwrap()
+        }
+        None => writeln!(log, "No ACPI RSDP found").unwrap(),
+    }
+
+    //メーカーや搭載メモリの情報はSMBIOSからたどる
+    let smbios = find_smbios(system_table);
+    match smbios {
+        Some((address, version)) => {
+            writeln!(log, "SMBIOS {} entry point at {:#x}", version, address).unwrap()
+        }
+        None => writeln!(log, "No SMBIOS entry point found").unwrap(),
+    }
+
+    //ロードオプションがあれば設定ファイルより優先する
+    let (command_line_text, command_line_source) = match load_options(boot_services, handle) {
+        Ok(Some(options)) => (options, "load options"),
+        Ok(None) => (config.command_line.clone(), Config::PATH),
+        Err(reason) => {
+            writeln!(log, "Ignoring load options: {}", reason).unwrap();
+            (config.command_line.clone(), Config::PATH)
+        }
+    };
+    if !command_line_text.is_empty() {
+        writeln!(
+            log,
+            "Command line from {}: {}",
+            command_line_source, command_line_text
+        )
+        .unwrap();
+    }
+    //コマンドラインもLOADER_DATAに置いて渡す
+    let command_line = copy_to_loader_data(boot_services, command_line_text.as_bytes());
+    let command_line_len = command_line_text.len();
+
+    //UEFIのスタックはBOOT_SERVICES_DATAなのでカーネル用に確保し直す
+    //Config::parseで上限を確認しているが念のため
+    let stack_pages = match config
+        .stack_size
+        .checked_mul(1024)
+        .and_then(|size| size.checked_add(paging::PAGE_SIZE - 1))
+    {
+        Some(size) => (size / paging::PAGE_SIZE) as usize,
+        None => {
+            writeln!(
+                log,
+                "Kernel stack size is too large: {} KiB",
+                config.stack_size
+            )
+            .unwrap();
+            return Status::LOAD_ERROR;
+        }
+    };
+    let stack_base = match boot_services
+        .allocate_pages(AllocateType::AnyPages, MemoryType::LOADER_DATA, stack_pages)
+        .log_warning()
+    {
+        Ok(stack_base) => stack_base,
+        Err(e) => {
+            writeln!(log, "Failed to allocate the kernel stack: {:?}", e.status()).unwrap();
+            return e.status();
+        }
+    };
+    //Argumentもスタックではなく後で解放されないところに置く
+    let args_buffer = unsafe {
+        slice::from_raw_parts_mut(
+            boot_services
+                .allocate_pool(MemoryType::LOADER_DATA, ARGS_BUFFER_SIZE * 8)
+                .unwrap_success() as *mut u64,
+            ARGS_BUFFER_SIZE,
+        )
+    };
+
+    //切り替えるのはexit_boot_servicesの後だが、ページテーブルの確保はその前にする
+    let physical_memory_end = physical_memory_end(boot_services);
+    let page_table = match build_page_table(
+        boot_services,
+        &elf_file,
+        kernel_base_addr,
+        kernel_virtual_base,
+        physical_memory_end,
+        &arg_frame_buffer,
+        stack_base,
+        stack_pages,
+    ) {
+        Ok(page_table) => page_table,
+        Err(e) => {
+            writeln!(log, "Failed to build page tables: {}", e).unwrap();
+            return Status::OUT_OF_RESOURCES;
+        }
+    };
+    log.phase("page tables");
+
+    //メニューを出したならもう待っている
+    if config.timeout > 0 && !show_menu {
+        wait_timeout(
+            boot_services,
+            log.stdout(),
+            system_table.stdin(),
+            config.timeout,
+        );
+    }
+
+    writeln!(log, "Bye").unwrap();
+    //exit_boot_servicesの後はファイルに書けない
+    if let Err(status) = log.flush(root_dir) {
+        writeln!(log, "Failed to write {}: {:?}", BootLog::PATH, status).unwrap();
+    }
+    let (runtime_table, arg_memory_map) = match exit_boot_services(handle, system_table) {
+        Ok(result) => result,
+        Err(status) => {
+            //失敗したならまだブートサービスが使える
+            writeln!(log, "Failed to exit boot services: {:?}", status).unwrap();
+            return status;
+        }
+    };
+    //もうUEFIのコンソールは使えないので、ここから先のパニックはフレームバッファとCOM1に出す
+    panic::use_frame_buffer(arg_frame_buffer, arg_frame_buffer_config);
+    //安全性: 低位はidentity mapしているので実行中のコードとスタックはそのまま見える
+    unsafe {
+        paging::activate(page_table);
+    }
+
+    let kernel_entry = elf_file.entry(kernel_virtual_base);
+    //Argumentの直後にタグが並ぶのでまとめて一つのバッファに書き込む
+    let mut args_builder = arg::ArgumentBuilder::new(args_buffer);
+    //タグの数は決まっているのでバッファが足りなくなることはない
+    args_builder
+        .push(arg::FrameBufferTag::new(
+            arg_frame_buffer,
+            arg_frame_buffer_config,
+        ))
+        .unwrap();
+    args_builder
+        .push(arg::SymbolTableTag::new(arg_symbol_table))
+        .unwrap();
+    args_builder
+        .push(arg::MemoryMapTag::new(arg_memory_map))
+        .unwrap();
+    if command_line_len > 0 {
+        args_builder
+            .push(arg::CommandLineTag::new(command_line, command_line_len))
+            .unwrap();
+    }
+    if let Some((address, _)) = rsdp {
+        args_builder.push(arg::AcpiTag::new(address)).unwrap();
+    }
+    if let Some((address, _)) = smbios {
+        args_builder.push(arg::SmbiosTag::new(address)).unwrap();
+    }
+    //カーネルから時刻の取得やリセットに使う
+    let runtime_services = unsafe { runtime_table.runtime_services() } as *const _ as u64;
+    args_builder
+        .push(arg::RuntimeServicesTag::new(runtime_services))
+        .unwrap();
+    for module in modules {
+        args_builder.push(module).unwrap();
+    }
+    args_builder
+        .push(arg::KernelDigestTag::new(kernel_digest))
+        .unwrap();
+    args_builder
+        .push(arg::PagingTag::new(
+            page_table,
+            paging::PHYSICAL_OFFSET,
+            physical_memory_end,
+            kernel_base_addr,
+            kernel_virtual_base,
+            kernel_page_count as u64 * paging::PAGE_SIZE,
+        ))
+        .unwrap();
+    let args = args_builder.finish();
+    //安全性: スタックはbuild_page_tableでKERNEL_STACK_TOPの下に写してある
+    unsafe { jump_to_kernel(kernel_entry, args, paging::KERNEL_STACK_TOP) }
+}
+
+//RSPをカーネルのスタックに切り替えてからカーネルを呼ぶ
+//callで戻りアドレスを積むのでカーネルからはSystem V ABIどおりに見える
+unsafe fn jump_to_kernel(entry: u64, args: *const arg::Argument, stack_top: u64) -> ! {
+    asm!(
+        "mov rsp, {}",
+        "xor ebp, ebp",
+        "call {}",
+        in(reg) stack_top,
+        in(reg) entry,
+        in("rdi") args,
+        options(noreturn)
+    );
+}
+
+fn memory_map_buffer_size(boot_services: &BootServices, slack: usize) -> usize {
+    boot_services.memory_map_size() + slack * mem::size_of::<MemoryDescriptor>()
+}
+
+//identity mapする範囲、RAMの終わりまでと、低位のMMIOがあるので少なくとも4GiBは写す
+//高位のMMIOや予約領域まで写すとページテーブルが大きくなりすぎる
+fn physical_memory_end(boot_services: &BootServices) -> u64 {
+    const LOW_MEMORY_END: u64 = 0x1_0000_0000;
+    let mut buffer = alloc::vec![0; memory_map_buffer_size(boot_services, MEMORY_MAP_SLACK)];
+    let (_memory_map_key, descriptor_iter) = boot_services.memory_map(&mut buffer).unwrap_success();
+    let end = descriptor_iter
+        .filter(|descriptor| memory_map::is_ram(descriptor.ty))
+        .map(|descriptor| descriptor.phys_start + descriptor.page_count * paging::PAGE_SIZE)
+        .max()
+        .unwrap_or(0)
+        .max(LOW_MEMORY_END);
+    (end + paging::LARGE_PAGE_SIZE - 1) & !(paging::LARGE_PAGE_SIZE - 1)
+}
+
+//低位のidentity map、物理メモリ全体のPHYSICAL_OFFSETからのマップ、フレームバッファ、
+//カーネルのセグメントを写したページテーブルを作る
+fn build_page_table(
+    boot_services: &BootServices,
+    elf_file: &Elf,
+    kernel_base_addr: u64,
+    kernel_virtual_base: u64,
+    physical_memory_end: u64,
+    frame_buffer: &arg::FrameBuffer,
+    stack_base: u64,
+    stack_pages: usize,
+) -> Result<u64, PagingError> {
+    let mut page_table = PageTableBuilder::new(|| {
+        let frame = boot_services
+            .allocate_pages(AllocateType::AnyPages, MemoryType::LOADER_DATA, 1)
+            .log_warning()
+            .ok()?;
+        //安全性はallocate_pagesに依存
+        unsafe {
+            ptr::write_bytes(frame as *mut u8, 0, paging::PAGE_SIZE as usize);
+        }
+        Some(frame)
+    })?;
+    //ブートローダのコードとスタック、カーネルに渡すデータは低位にある
+    page_table.map_large_range(0, 0, physical_memory_end, PageFlags::READ_WRITE_EXECUTE)?;
+    page_table.map_large_range(
+        paging::PHYSICAL_OFFSET,
+        0,
+        physical_memory_end,
+        PageFlags::READ_WRITE,
+    )?;
+
+    //フレームバッファはメモリマップの範囲より上にあることがある
+    let frame_buffer_start =
+        (frame_buffer.base as u64 & !(paging::LARGE_PAGE_SIZE - 1)).max(physical_memory_end);
+    let frame_buffer_end =
+        (frame_buffer.base as u64 + frame_buffer.size as u64 + paging::LARGE_PAGE_SIZE - 1)
+            & !(paging::LARGE_PAGE_SIZE - 1);
+    if frame_buffer_start < frame_buffer_end {
+        let size = frame_buffer_end - frame_buffer_start;
+        page_table.map_large_range(
+            frame_buffer_start,
+            frame_buffer_start,
+            size,
+            PageFlags::READ_WRITE,
+        )?;
+        page_table.map_large_range(
+            paging::PHYSICAL_OFFSET + frame_buffer_start,
+            frame_buffer_start,
+            size,
+            PageFlags::READ_WRITE,
+        )?;
+    }
+
+    //ガードページのKERNEL_STACK_TOP - (stack_pages + 1) * PAGE_SIZEは写さない
+    let stack_bottom = paging::KERNEL_STACK_TOP - stack_pages as u64 * paging::PAGE_SIZE;
+    for page in 0..stack_pages as u64 {
+        page_table.map(
+            stack_bottom + page * paging::PAGE_SIZE,
+            stack_base + page * paging::PAGE_SIZE,
+            PageFlags::READ_WRITE,
+        )?;
+    }
+
+    //セグメントがページを共有していたら許可を足し合わせる
+    let image_start = elf_file.calculate_base_addr();
+    let load_bias = elf_file.load_bias(kernel_base_addr);
+    let virtual_bias = elf_file.load_bias(kernel_virtual_base);
+    for page in 0..elf_file.calculate_page_count() as u64 {
+        let addr = image_start + page * paging::PAGE_SIZE;
+        let flags = elf_file
+            .program_header_iter()
+            .filter(|program_header| program_header.type_is_load())
+            .filter(|program_header| {
+                let start = program_header.p_vaddr() & !(paging::PAGE_SIZE - 1);
+                let end = program_header.p_vaddr() + program_header.p_memsz();
+                addr >= start && addr < end
+            })
+            .fold(None, |flags: Option<PageFlags>, program_header| {
+                let flags = flags.unwrap_or(PageFlags {
+                    writable: false,
+                    executable: false,
+                });
+                Some(PageFlags {
+                    writable: flags.writable || program_header.is_writable(),
+                    executable: flags.executable || program_header.is_executable(),
+                })
+            });
+        if let Some(flags) = flags {
+            page_table.map(
+                addr.wrapping_add(virtual_bias),
+                addr.wrapping_add(load_bias),
+                flags,
+            )?;
+        }
+    }
+    Ok(page_table.pml4())
+}
+
+//exit_boot_services後はメモリを確保できないので、メモリマップとカーネルに渡す記述子の置き場所を先に確保する
+//メモリマップのキーが古くなった場合はuefi-rsがやり直すが、バッファが足りない場合は大きくしてこちらでやり直す
+fn exit_boot_services(
+    handle: Handle,
+    system_table: &SystemTable<Boot>,
+) -> Result<(SystemTable<Runtime>, arg::MemoryMap), Status> {
+    let boot_services = system_table.boot_services();
+    let mut slack = MEMORY_MAP_SLACK;
+    loop {
+        let buffer_size = memory_map_buffer_size(boot_services, slack);
+        let buffer = boot_services
+            .allocate_pool(MemoryType::LOADER_DATA, buffer_size)
+            .log_warning()
+            .map_err(|e| e.status())?;
+        //バッファに入る記述子の数より多くなることはない
+        let capacity = buffer_size / mem::size_of::<MemoryDescriptor>();
+        let arg_descriptors = boot_services
+            .allocate_pool(
+                MemoryType::LOADER_DATA,
+                capacity * mem::size_of::<arg::MemoryDescriptor>(),
+            )
+            .log_warning()
+            .map_err(|e| e.status())? as *mut arg::MemoryDescriptor;
+        //安全性はallocate_poolに依存
+        let memory_map_buffer = unsafe { slice::from_raw_parts_mut(buffer, buffer_size) };
+
+        //失敗したらやり直すので複製を渡す、成功した後は元のシステムテーブルを使わない
+        let result = unsafe { system_table.unsafe_clone() }
+            .exit_boot_services(handle, memory_map_buffer)
+            .log_warning();
+        match result {
+            Ok((runtime_table, descriptor_iter)) => {
+                let mut len = 0;
+                for descriptor in descriptor_iter.take(capacity) {
+                    //安全性はallocate_poolに依存
+                    unsafe {
+                        arg_descriptors
+                            .add(len)
+                            .write(memory_map::to_arg_descriptor(descriptor));
+                    }
+                    len += 1;
+                }
+                let memory_map = arg::MemoryMap {
+                    descriptors: arg_descriptors,
+                    len,
+                };
+                return Ok((runtime_table, memory_map));
+            }
+            //メモリマップの取得で失敗しただけなのでブートサービスはまだ使える
+            Err(e) if e.status() == Status::BUFFER_TOO_SMALL => {
+                boot_services
+                    .free_pool(arg_descriptors as *mut u8)
+                    .log_warning()
+                    .map_err(|e| e.status())?;
+                boot_services
+                    .free_pool(buffer)
+                    .log_warning()
+                    .map_err(|e| e.status())?;
+                slack *= 2;
+            }
+            Err(e) => return Err(e.status()),
+        }
+    }
+}
+
+//構成テーブルからRSDPを探す、ACPI 2.0のものがあればそちらを使う
+fn find_rsdp(system_table: &SystemTable<Boot>) -> Option<(u64, &'static str)> {
+    let config_table = system_table.config_table();
+    let find = |guid| {
+        config_table
+            .iter()
+            .find(|entry| entry.guid == guid)
+            .map(|entry| entry.address as u64)
+    };
+    find(cfg::ACPI2_GUID)
+        .map(|address| (address, "2.0"))
+        .or_else(|| find(cfg::ACPI_GUID).map(|address| (address, "1.0")))
+}
+
+fn find_smbios(system_table: &SystemTable<Boot>) -> Option<(u64, &'static str)> {
+    let config_table = system_table.config_table();
+    let find = |guid| {
+        config_table
+            .iter()
+            .find(|entry| entry.guid == guid)
+            .map(|entry| entry.address as u64)
+    };
+    find(cfg::SMBIOS3_GUID)
+        .map(|address| (address, "3.0"))
+        .or_else(|| find(cfg::SMBIOS_GUID).map(|address| (address, "2.x")))
+}
+
+//UEFIのロードオプション(UEFIシェルの引数やブートエントリのオプション)
+//シェルから起動した場合は先頭がイメージのパスなので取り除く
+fn load_options(
+    boot_services: &BootServices,
+    handle: Handle,
+) -> Result<Option<String>, &'static str> {
+    let loaded_image = match boot_services
+        .handle_protocol::<LoadedImage>(handle)
+        .log_warning()
+    {
+        Ok(loaded_image) => loaded_image.get() as *const LoadedImageHead,
+        Err(_) => return Ok(None),
     };
-    kernel_entry(&args);
+    //安全性はhandle_protocolに依存
+    let (options, size) = unsafe {
+        (
+            (*loaded_image).load_options,
+            (*loaded_image).load_options_size as usize,
+        )
+    };
+    if options.is_null() || size == 0 {
+        return Ok(None);
+    }
+    //OVMFが作るBoot####はGUIDなどのバイナリを渡してくるので、文字列でなければ使わない
+    if size % 2 != 0 {
+        return Err("not UCS-2");
+    }
+    //安全性: LoadOptionsSizeの範囲だけ読む
+    let options = unsafe { slice::from_raw_parts(options, size / 2) };
+    let options = match options.iter().position(|&c| c == 0) {
+        Some(end) => &options[..end],
+        None => return Err("not NUL-terminated"),
+    };
+    let mut text = String::new();
+    for c in char::decode_utf16(options.iter().cloned()) {
+        match c {
+            Ok(c) if c == '\t' || !c.is_control() => text.push(c),
+            _ => return Err("not printable"),
+        }
+    }
+
+    let mut tokens = text.split_whitespace().peekable();
+    if let Some(first) = tokens.peek() {
+        if first.to_ascii_lowercase().ends_with(".efi") {
+            tokens.next();
+        }
+    }
+    let command_line = tokens.collect::<Vec<_>>().join(" ");
+    if command_line.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(command_line))
+    }
+}
+
+//EFI_LOADED_IMAGE_PROTOCOLの先頭
+//uefi-rsのload_optionsはLoadOptionsSizeを見ずにNULまで読むので自分で読む
+#[repr(C)]
+struct LoadedImageHead {
+    revision: u32,
+    parent_handle: *const c_void,
+    system_table: *const c_void,
+    device_handle: *const c_void,
+    file_path: *const c_void,
+    reserved: *const c_void,
+    load_options_size: u32,
+    load_options: *const u16,
+}
+
+//<カーネルのパス>.sha256があれば比べる、無ければ何もしない
+fn verify_digest(
+    root_dir: &mut Directory,
+    kernel_path: &str,
+    digest: &[u8; sha256::DIGEST_SIZE],
+) -> Result<(), String> {
+    let digest_path = alloc::format!("{}.sha256", kernel_path);
+    let text = match file::read_file(root_dir, &digest_path) {
+        Ok(text) => text,
+        Err(Status::NOT_FOUND) => return Ok(()),
+        Err(status) => {
+            return Err(alloc::format!(
+                "failed to read {}: {:?}",
+                digest_path,
+                status
+            ))
+        }
+    };
+    let expected = str::from_utf8(&text)
+        .ok()
+        .and_then(sha256::parse_hex)
+        .ok_or_else(|| alloc::format!("{} is not a SHA-256 digest", digest_path))?;
+    if expected != *digest {
+        return Err(alloc::format!(
+            "SHA-256 mismatch (expected {}, got {})",
+            HexDigest(&expected),
+            HexDigest(digest)
+        ));
+    }
+    Ok(())
+}
+
+struct HexDigest<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for HexDigest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+//ルートディレクトリにある*.elf
+fn find_kernels(root_dir: &mut Directory) -> Vec<String> {
+    let mut kernels = file::file_names(root_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| name.to_ascii_lowercase().ends_with(".elf"))
+        .map(|name| alloc::format!("\\{}", name))
+        .collect::<Vec<_>>();
+    kernels.sort();
+    kernels
+}
+
+//timeout秒待ってから起動する、何かキーを押せばすぐに起動する
+fn wait_timeout(
+    boot_services: &BootServices,
+    stdout: &mut Output,
+    stdin: &mut Input,
+    timeout: u64,
+) {
+    for remaining in (1..=timeout).rev() {
+        write!(stdout, "\rBooting in {} seconds... ", remaining).unwrap();
+        for _ in 0..10 {
+            if let Ok(Some(_)) = stdin.read_key().log_warning() {
+                writeln!(stdout).unwrap();
+                return;
+            }
+            boot_services.stall(100_000);
+        }
+    }
+    writeln!(stdout).unwrap();
+}
+
+//SimpleFileSystemを持つボリュームを順に見て、pathがある最初のものとその番号を返す
+fn find_volume(boot_services: &BootServices, path: &str) -> Option<(usize, Directory)> {
+    //feature = exts
+    let handles = boot_services
+        .find_handles::<SimpleFileSystem>()
+        .log_warning()
+        .ok()?;
+    handles.iter().enumerate().find_map(|(index, &handle)| {
+        let file_system = boot_services
+            .handle_protocol::<SimpleFileSystem>(handle)
+            .log_warning()
+            .ok()?
+            .get();
+        //安全性はhandle_protocolに依存
+        let mut root_dir = unsafe { (*file_system).open_volume() }.log_warning().ok()?;
+        if file::exists(&mut root_dir, path) {
+            Some((index, root_dir))
+        } else {
+            None
+        }
+    })
+}
+
+//LOADER_DATAはexit_boot_services後も残るのでカーネルに渡せる
+fn copy_to_loader_data(boot_services: &BootServices, data: &[u8]) -> *const u8 {
+    if data.is_empty() {
+        return ptr::null();
+    }
+    let buffer = boot_services
+        .allocate_pool(MemoryType::LOADER_DATA, data.len())
+        .unwrap_success();
+    //安全性はallocate_poolに依存
+    unsafe {
+        ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+    }
+    buffer
+}
+
+//ファイルをLOADER_DATAのページに読み込む
+//名前はパスの最後の部分
+fn load_module(
+    boot_services: &BootServices,
+    root_dir: &mut Directory,
+    path: &str,
+) -> Result<arg::ModuleTag, Status> {
+    let data = file::read_file(root_dir, path)?;
+
+    let buffer = if data.is_empty() {
+        ptr::null_mut()
+    } else {
+        let page_count = (data.len() + 0xfff) / 0x1000;
+        let buffer = boot_services
+            .allocate_pages(AllocateType::AnyPages, MemoryType::LOADER_DATA, page_count)
+            .log_warning()
+            .map_err(|e| e.status())? as *mut u8;
+        //安全性はallocate_pagesに依存
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+        }
+        buffer
+    };
+    let name = path.rsplit('\\').next().unwrap_or(path);
+    Ok(arg::ModuleTag::new(
+        buffer,
+        data.len(),
+        copy_to_loader_data(boot_services, name.as_bytes()),
+        name.len(),
+    ))
+}
+
+//設定ファイルは無くてもよい、読めなければ今までと同じ動作にする
+fn load_config(root_dir: &mut Directory, log: &mut BootLog) -> Config {
+    let text = match file::read_file(root_dir, Config::PATH) {
+        Ok(text) => text,
+        Err(Status::NOT_FOUND) => return Config::default(),
+        Err(status) => {
+            writeln!(log, "Ignoring {}: {:?}", Config::PATH, status).unwrap();
+            return Config::default();
+        }
+    };
+
+    let text = match str::from_utf8(&text) {
+        Ok(text) => text,
+        Err(_) => {
+            writeln!(log, "Ignoring {}: not UTF-8", Config::PATH).unwrap();
+            return Config::default();
+        }
+    };
+    match Config::parse(text) {
+        Ok(config) => config,
+        Err(e) => {
+            writeln!(log, "Ignoring {}: {}", Config::PATH, e).unwrap();
+            Config::default()
+        }
+    }
+}
+
+//\memmapはCSV、\memmap.txtとコンソールには人が読む形で出す
+fn dump_memory_map<'a>(
+    root_dir: &mut Directory,
+    log: &mut BootLog,
+    descriptor_iter: impl Iterator<Item = &'a MemoryDescriptor>,
+) {
+    let descriptors: Vec<&MemoryDescriptor> = descriptor_iter.collect();
+
+    let mut csv = String::from("Type, PhysicalStart, NumberOfPages, Attribute\n");
+    for descriptor in descriptors.iter() {
+        //Attributeは複数のビットが立つのでそのまま出す
+        writeln!(
+            csv,
+            "{:016x},{:016x},{:016x},{:016x}",
+            memory_map::to_arg_memory_type(descriptor.ty) as u32,
+            descriptor.phys_start,
+            descriptor.page_count,
+            descriptor.att.bits()
+        )
+        .unwrap();
+    }
+    if let Err(status) = file::write_file(root_dir, "\\memmap", csv.as_bytes()) {
+        writeln!(log, "Failed to write \\memmap: {:?}", status).unwrap();
+    }
+
+    let mut text = String::new();
+    for descriptor in descriptors.iter() {
+        writeln!(text, "{}", memory_map::Line(descriptor)).unwrap();
+    }
+    writeln!(
+        text,
+        "Usable memory: {}",
+        memory_map::Size(memory_map::usable_bytes(descriptors.iter().copied()))
+    )
+    .unwrap();
+    if let Err(status) = file::write_file(root_dir, "\\memmap.txt", text.as_bytes()) {
+        writeln!(log, "Failed to write \\memmap.txt: {:?}", status).unwrap();
+    }
+    write!(log, "{}", text).unwrap();
 }
@@ -0,0 +1,181 @@
+use crate::arg;
+use core::fmt;
+use uefi::table::boot::{MemoryDescriptor, MemoryType};
+
+const PAGE_SIZE: u64 = 0x1000;
+
+pub fn to_arg_descriptor(descriptor: &MemoryDescriptor) -> arg::MemoryDescriptor {
+    arg::MemoryDescriptor {
+        memory_type: to_arg_memory_type(descriptor.ty),
+        physical_start: descriptor.phys_start,
+        page_count: descriptor.page_count,
+        attribute: descriptor.att.bits(),
+    }
+}
+
+pub fn to_arg_memory_type(memory_type: MemoryType) -> arg::MemoryType {
+    match memory_type {
+        MemoryType::RESERVED => arg::MemoryType::Reserved,
+        MemoryType::LOADER_CODE => arg::MemoryType::LoaderCode,
+        MemoryType::LOADER_DATA => arg::MemoryType::LoaderData,
+        MemoryType::BOOT_SERVICES_CODE => arg::MemoryType::BootServicesCode,
+        MemoryType::BOOT_SERVICES_DATA => arg::MemoryType::BootServicesData,
+        MemoryType::RUNTIME_SERVICES_CODE => arg::MemoryType::RuntimeServicesCode,
+        MemoryType::RUNTIME_SERVICES_DATA => arg::MemoryType::RuntimeServicesData,
+        MemoryType::CONVENTIONAL => arg::MemoryType::Conventional,
+        MemoryType::UNUSABLE => arg::MemoryType::Unusable,
+        MemoryType::ACPI_RECLAIM => arg::MemoryType::AcpiReclaim,
+        MemoryType::ACPI_NON_VOLATILE => arg::MemoryType::AcpiNonVolatile,
+        MemoryType::MMIO => arg::MemoryType::Mmio,
+        MemoryType::MMIO_PORT_SPACE => arg::MemoryType::MmioPortSpace,
+        MemoryType::PAL_CODE => arg::MemoryType::PalCode,
+        MemoryType::PERSISTENT_MEMORY => arg::MemoryType::PersistentMemory,
+        _ => arg::MemoryType::Unknown,
+    }
+}
+
+pub fn type_name(memory_type: MemoryType) -> &'static str {
+    match memory_type {
+        MemoryType::RESERVED => "RESERVED",
+        MemoryType::LOADER_CODE => "LOADER_CODE",
+        MemoryType::LOADER_DATA => "LOADER_DATA",
+        MemoryType::BOOT_SERVICES_CODE => "BOOT_SERVICES_CODE",
+        MemoryType::BOOT_SERVICES_DATA => "BOOT_SERVICES_DATA",
+        MemoryType::RUNTIME_SERVICES_CODE => "RUNTIME_SERVICES_CODE",
+        MemoryType::RUNTIME_SERVICES_DATA => "RUNTIME_SERVICES_DATA",
+        MemoryType::CONVENTIONAL => "CONVENTIONAL",
+        MemoryType::UNUSABLE => "UNUSABLE",
+        MemoryType::ACPI_RECLAIM => "ACPI_RECLAIM",
+        MemoryType::ACPI_NON_VOLATILE => "ACPI_NON_VOLATILE",
+        MemoryType::MMIO => "MMIO",
+        MemoryType::MMIO_PORT_SPACE => "MMIO_PORT_SPACE",
+        MemoryType::PAL_CODE => "PAL_CODE",
+        MemoryType::PERSISTENT_MEMORY => "PERSISTENT_MEMORY",
+        _ => "UNKNOWN",
+    }
+}
+
+//exit_boot_servicesの後にカーネルが使ってよいもの
+//LOADER_*はカーネル自身や引数が入っているが、いずれは使える
+pub fn is_usable(memory_type: MemoryType) -> bool {
+    match memory_type {
+        MemoryType::CONVENTIONAL
+        | MemoryType::LOADER_CODE
+        | MemoryType::LOADER_DATA
+        | MemoryType::BOOT_SERVICES_CODE
+        | MemoryType::BOOT_SERVICES_DATA => true,
+        _ => false,
+    }
+}
+
+//RAMが載っているもの、MMIOや予約領域は含めない
+pub fn is_ram(memory_type: MemoryType) -> bool {
+    match memory_type {
+        MemoryType::RUNTIME_SERVICES_CODE
+        | MemoryType::RUNTIME_SERVICES_DATA
+        | MemoryType::ACPI_RECLAIM
+        | MemoryType::ACPI_NON_VOLATILE
+        | MemoryType::PERSISTENT_MEMORY => true,
+        _ => is_usable(memory_type),
+    }
+}
+
+pub fn usable_bytes<'a>(descriptors: impl Iterator<Item = &'a MemoryDescriptor>) -> u64 {
+    descriptors
+        .filter(|descriptor| is_usable(descriptor.ty))
+        .map(|descriptor| descriptor.page_count * PAGE_SIZE)
+        .sum()
+}
+
+//EFI_MEMORY_*のビット、複数立っていることがほとんど
+const ATTRIBUTE_NAMES: [(u64, &str); 12] = [
+    (0x1, "UC"),
+    (0x2, "WC"),
+    (0x4, "WT"),
+    (0x8, "WB"),
+    (0x10, "UCE"),
+    (0x1000, "WP"),
+    (0x2000, "RP"),
+    (0x4000, "XP"),
+    (0x8000, "NV"),
+    (0x10000, "MORE_RELIABLE"),
+    (0x20000, "RO"),
+    (0x8000_0000_0000_0000, "RUNTIME"),
+];
+
+//"UC|WC|WT|WB"のように立っているビットを並べる、名前のないビットは16進で出す
+pub struct Attributes(pub u64);
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rest = self.0;
+        let mut first = true;
+        for &(bit, name) in ATTRIBUTE_NAMES.iter() {
+            if rest & bit == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+            rest &= !bit;
+            first = false;
+        }
+        if rest != 0 {
+            if !first {
+                f.write_str("|")?;
+            }
+            write!(f, "{:#x}", rest)?;
+            first = false;
+        }
+        if first {
+            f.write_str("-")?;
+        }
+        Ok(())
+    }
+}
+
+//1MiB未満はKiB、それ以上はMiBで出す
+pub struct Size(pub u64);
+
+impl Size {
+    const KIB: u64 = 1024;
+    const MIB: u64 = 1024 * 1024;
+
+    fn value_and_unit(&self) -> (u64, &'static str) {
+        if self.0 < Size::MIB {
+            (self.0 / Size::KIB, "KiB")
+        } else {
+            (self.0 / Size::MIB, "MiB")
+        }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (value, unit) = self.value_and_unit();
+        write!(f, "{} {}", value, unit)
+    }
+}
+
+//人が読む用の一行、"CONVENTIONAL 0x000000100000-0x0000007fffff 7 MiB UC|WB"のような形
+pub struct Line<'a>(pub &'a MemoryDescriptor);
+
+impl fmt::Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let descriptor = self.0;
+        let size = descriptor.page_count * PAGE_SIZE;
+        //桁をそろえるために数と単位を分けて出す
+        let (value, unit) = Size(size).value_and_unit();
+        write!(
+            f,
+            "{:<21} {:#014x}-{:#014x} {:>7} {} {}",
+            type_name(descriptor.ty),
+            descriptor.phys_start,
+            (descriptor.phys_start + size).saturating_sub(1),
+            value,
+            unit,
+            Attributes(descriptor.att.bits())
+        )
+    }
+}
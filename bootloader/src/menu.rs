@@ -0,0 +1,63 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Write;
+use uefi::prelude::*;
+use uefi::proto::console::text::{Input, Key, Output, ScanCode};
+
+//起動するカーネルを選ぶメニュー
+//上下キーで選んでEnterで決定、timeout秒たつと選んでいるものを起動する
+//何かキーを押したらカウントダウンをやめる
+pub fn select(
+    boot_services: &BootServices,
+    stdout: &mut Output,
+    stdin: &mut Input,
+    entries: &[String],
+    default: usize,
+    timeout: u64,
+) -> usize {
+    let mut selected = default.min(entries.len() - 1);
+    //100ミリ秒単位
+    let mut remaining = Some(timeout * 10);
+    draw(stdout, entries, selected, Some(timeout));
+    loop {
+        if let Ok(Some(key)) = stdin.read_key().log_warning() {
+            match key {
+                Key::Special(ScanCode::UP) => {
+                    selected = (selected + entries.len() - 1) % entries.len()
+                }
+                Key::Special(ScanCode::DOWN) => selected = (selected + 1) % entries.len(),
+                Key::Printable(c) if char::from(c) == '\r' => break,
+                _ => (),
+            }
+            remaining = None;
+            draw(stdout, entries, selected, None);
+        }
+        if let Some(ticks) = remaining {
+            if ticks == 0 {
+                break;
+            }
+            if ticks % 10 == 0 {
+                draw(stdout, entries, selected, Some(ticks / 10));
+            }
+            remaining = Some(ticks - 1);
+        }
+        boot_services.stall(100_000);
+    }
+    stdout.clear().unwrap_success();
+    selected
+}
+
+fn draw(stdout: &mut Output, entries: &[String], selected: usize, remaining: Option<u64>) {
+    stdout.clear().unwrap_success();
+    writeln!(stdout, "Select a kernel with Up/Down and press Enter").unwrap();
+    writeln!(stdout).unwrap();
+    for (i, entry) in entries.iter().enumerate() {
+        let marker = if i == selected { '>' } else { ' ' };
+        writeln!(stdout, " {} {}", marker, entry).unwrap();
+    }
+    writeln!(stdout).unwrap();
+    if let Some(remaining) = remaining {
+        writeln!(stdout, "Booting in {} seconds...", remaining).unwrap();
+    }
+}
@@ -0,0 +1,201 @@
+use core::fmt;
+
+//ブートローダが作る4段のページテーブル
+//exit_boot_servicesの前に作るのでテーブルは物理アドレスのまま読み書きする
+pub const PAGE_SIZE: u64 = 0x1000;
+pub const LARGE_PAGE_SIZE: u64 = 0x20_0000;
+
+//位置独立なカーネルはここに置く
+pub const KERNEL_BASE: u64 = 0xffff_ffff_8000_0000;
+//物理メモリ全体をこのオフセットに写す
+pub const PHYSICAL_OFFSET: u64 = 0xffff_8000_0000_0000;
+//カーネルのスタックはここから下に伸びる
+//一番下の更に下のページは写さずにガードページにする
+pub const KERNEL_STACK_TOP: u64 = 0xffff_ffff_0000_0000;
+
+const PRESENT: u64 = 1;
+const WRITABLE: u64 = 1 << 1;
+const HUGE_PAGE: u64 = 1 << 7;
+const NO_EXECUTE: u64 = 1 << 63;
+const ADDRESS_MASK: u64 = 0x000f_ffff_ffff_f000;
+const ENTRY_COUNT: usize = 512;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PageFlags {
+    pub writable: bool,
+    pub executable: bool,
+}
+
+impl PageFlags {
+    pub const READ_WRITE_EXECUTE: PageFlags = PageFlags {
+        writable: true,
+        executable: true,
+    };
+    pub const READ_WRITE: PageFlags = PageFlags {
+        writable: true,
+        executable: false,
+    };
+
+    fn bits(&self) -> u64 {
+        let mut bits = PRESENT;
+        if self.writable {
+            bits |= WRITABLE;
+        }
+        if !self.executable {
+            bits |= NO_EXECUTE;
+        }
+        bits
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PagingError {
+    OutOfMemory,
+    Misaligned(u64),
+}
+
+impl fmt::Display for PagingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PagingError::OutOfMemory => write!(f, "out of memory for page tables"),
+            PagingError::Misaligned(addr) => write!(f, "address {:#x} is not aligned", addr),
+        }
+    }
+}
+
+//allocate_frameは0で埋めた4KiBのページの物理アドレスを返す
+pub struct PageTableBuilder<A: FnMut() -> Option<u64>> {
+    pml4: u64,
+    allocate_frame: A,
+}
+
+impl<A: FnMut() -> Option<u64>> PageTableBuilder<A> {
+    pub fn new(mut allocate_frame: A) -> Result<PageTableBuilder<A>, PagingError> {
+        let pml4 = allocate_frame().ok_or(PagingError::OutOfMemory)?;
+        Ok(PageTableBuilder {
+            pml4,
+            allocate_frame,
+        })
+    }
+
+    //CR3に入れる値
+    pub fn pml4(&self) -> u64 {
+        self.pml4
+    }
+
+    //2MiBページで写す、同じ場所を写し直したら上書きする
+    pub fn map_large_range(
+        &mut self,
+        virtual_addr: u64,
+        physical_addr: u64,
+        size: u64,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        if virtual_addr & (LARGE_PAGE_SIZE - 1) != 0 {
+            return Err(PagingError::Misaligned(virtual_addr));
+        }
+        if physical_addr & (LARGE_PAGE_SIZE - 1) != 0 {
+            return Err(PagingError::Misaligned(physical_addr));
+        }
+        let mut offset = 0;
+        while offset < size {
+            let pd = self.table(virtual_addr + offset, 2)?;
+            let index = table_index(virtual_addr + offset, 1);
+            //安全性はtableに依存
+            unsafe {
+                pd.add(index)
+                    .write((physical_addr + offset) | flags.bits() | HUGE_PAGE);
+            }
+            offset += LARGE_PAGE_SIZE;
+        }
+        Ok(())
+    }
+
+    //4KiBページで写す、2MiBページの中なら分割して上書きする
+    pub fn map(
+        &mut self,
+        virtual_addr: u64,
+        physical_addr: u64,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        if virtual_addr & (PAGE_SIZE - 1) != 0 {
+            return Err(PagingError::Misaligned(virtual_addr));
+        }
+        if physical_addr & (PAGE_SIZE - 1) != 0 {
+            return Err(PagingError::Misaligned(physical_addr));
+        }
+        let pt = self.table(virtual_addr, 1)?;
+        let index = table_index(virtual_addr, 0);
+        //安全性はtableに依存
+        unsafe {
+            pt.add(index).write(physical_addr | flags.bits());
+        }
+        Ok(())
+    }
+
+    //virtual_addrを含むlevel段目のテーブル(4がPML4、1がPT)
+    //途中のテーブルが無ければ作る
+    fn table(&mut self, virtual_addr: u64, level: usize) -> Result<*mut u64, PagingError> {
+        let mut table = self.pml4 as *mut u64;
+        for current in (level + 1..=4).rev() {
+            let index = table_index(virtual_addr, current - 1);
+            //安全性はallocate_frameに依存
+            unsafe {
+                let entry = table.add(index).read();
+                let next = if entry & PRESENT == 0 {
+                    let frame = (self.allocate_frame)().ok_or(PagingError::OutOfMemory)?;
+                    table.add(index).write(frame | PRESENT | WRITABLE);
+                    frame
+                } else if entry & HUGE_PAGE != 0 {
+                    let frame = self.split(entry)?;
+                    table.add(index).write(frame | PRESENT | WRITABLE);
+                    frame
+                } else {
+                    entry & ADDRESS_MASK
+                };
+                table = next as *mut u64;
+            }
+        }
+        Ok(table)
+    }
+
+    //2MiBページを同じ許可の4KiBページ512個に分ける
+    fn split(&mut self, entry: u64) -> Result<u64, PagingError> {
+        let frame = (self.allocate_frame)().ok_or(PagingError::OutOfMemory)?;
+        let base = entry & ADDRESS_MASK;
+        let bits = entry & !ADDRESS_MASK & !HUGE_PAGE;
+        let table = frame as *mut u64;
+        for i in 0..ENTRY_COUNT {
+            //安全性はallocate_frameに依存
+            unsafe {
+                table.add(i).write((base + i as u64 * PAGE_SIZE) | bits);
+            }
+        }
+        Ok(frame)
+    }
+}
+
+//level 0がPT、3がPML4の添字
+fn table_index(virtual_addr: u64, level: usize) -> usize {
+    ((virtual_addr >> (12 + 9 * level)) & 0x1ff) as usize
+}
+
+//作ったページテーブルに切り替える
+//NXビットを使うのでEFER.NXEを、カーネルでも読み込み専用を守らせるのでCR0.WPを立てる
+//安全性: 実行中のコードとスタックがidentity mapされていること
+pub unsafe fn activate(pml4: u64) {
+    const EFER: u32 = 0xc000_0080;
+    const EFER_NXE: u64 = 1 << 11;
+    const CR0_WP: u64 = 1 << 16;
+
+    let (low, high): (u32, u32);
+    asm!("rdmsr", in("ecx") EFER, out("eax") low, out("edx") high);
+    let efer = (high as u64) << 32 | low as u64 | EFER_NXE;
+    asm!("wrmsr", in("ecx") EFER, in("eax") efer as u32, in("edx") (efer >> 32) as u32);
+
+    asm!("mov cr3, {}", in(reg) pml4);
+
+    let cr0: u64;
+    asm!("mov {}, cr0", out(reg) cr0);
+    asm!("mov cr0, {}", in(reg) cr0 | CR0_WP);
+}
@@ -0,0 +1,46 @@
+use crate::arg::{FrameBuffer, FrameBufferConfig};
+use crate::frame_buffer::FrameBufferWriter;
+use crate::serial::SerialPort;
+use core::fmt::Write;
+use core::panic::PanicInfo;
+use uefi::proto::console::text::Output;
+
+//パニックをどこに表示するか
+//exit_boot_servicesの前はUEFIのコンソール、後はフレームバッファとCOM1
+enum PanicOutput {
+    None,
+    Uefi(*mut Output<'static>),
+    FrameBuffer(FrameBuffer, FrameBufferConfig),
+}
+
+//シングルスレッドでしか使わない
+static mut PANIC_OUTPUT: PanicOutput = PanicOutput::None;
+
+//安全性: exit_boot_servicesを呼ぶまでstdoutが有効であること
+pub unsafe fn use_uefi_stdout(stdout: &mut Output) {
+    PANIC_OUTPUT = PanicOutput::Uefi(stdout as *mut Output as *mut Output<'static>);
+}
+
+//exit_boot_servicesの直後に呼ぶ
+pub fn use_frame_buffer(frame_buffer: FrameBuffer, config: FrameBufferConfig) {
+    unsafe {
+        PANIC_OUTPUT = PanicOutput::FrameBuffer(frame_buffer, config);
+    }
+}
+
+pub fn write(info: &PanicInfo) {
+    match unsafe { &PANIC_OUTPUT } {
+        PanicOutput::None => (),
+        PanicOutput::Uefi(stdout) => {
+            //パニック中なので表示できなくても何もしない
+            let stdout = unsafe { &mut **stdout };
+            let _ = writeln!(stdout, "{}", info);
+        }
+        PanicOutput::FrameBuffer(frame_buffer, config) => {
+            let mut serial_port = unsafe { SerialPort::new(SerialPort::COM1) };
+            let _ = writeln!(serial_port, "{}", info);
+            let mut writer = FrameBufferWriter::new(*frame_buffer, *config);
+            let _ = writeln!(writer, "{}", info);
+        }
+    }
+}
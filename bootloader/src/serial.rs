@@ -0,0 +1,63 @@
+use core::fmt;
+
+//16550互換のシリアルポート
+//QEMUなら-serial stdioで読める
+pub struct SerialPort {
+    base: u16,
+}
+
+impl SerialPort {
+    pub const COM1: u16 = 0x3f8;
+    //UARTが無い環境で止まらないように待つ回数を制限する
+    const MAX_WAIT: usize = 100_000;
+
+    //115200bps、8bit、パリティなし、ストップビット1
+    //安全性: baseが16550互換のUARTのポートであること
+    pub unsafe fn new(base: u16) -> SerialPort {
+        let port = SerialPort { base };
+        //割り込みを無効にする
+        port.out(1, 0x00);
+        //DLABを立てて分周比を設定する
+        port.out(3, 0x80);
+        port.out(0, 0x01);
+        port.out(1, 0x00);
+        port.out(3, 0x03);
+        //FIFOを有効にしてクリアする
+        port.out(2, 0xc7);
+        //DTR、RTS
+        port.out(4, 0x03);
+        port
+    }
+
+    pub fn write_byte(&mut self, byte: u8) {
+        for _ in 0..SerialPort::MAX_WAIT {
+            //送信保持レジスタが空くのを待つ
+            if unsafe { self.read(5) } & 0x20 != 0 {
+                break;
+            }
+        }
+        unsafe { self.out(0, byte) };
+    }
+
+    unsafe fn out(&self, offset: u16, data: u8) {
+        asm!("out dx, al", in("dx") self.base + offset, in("al") data);
+    }
+
+    unsafe fn read(&self, offset: u16) -> u8 {
+        let data: u8;
+        asm!("in al, dx", out("al") data, in("dx") self.base + offset);
+        data
+    }
+}
+
+impl fmt::Write for SerialPort {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            if byte == b'\n' {
+                self.write_byte(b'\r');
+            }
+            self.write_byte(byte);
+        }
+        Ok(())
+    }
+}
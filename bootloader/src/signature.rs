@@ -0,0 +1,56 @@
+use core::fmt;
+use ed25519_compact::{PublicKey, Signature};
+
+//ビルド時に環境変数KERNEL_PUBLIC_KEYで16進数64文字のEd25519公開鍵を埋め込む
+//無ければ署名を確認しない
+const PUBLIC_KEY: Option<&str> = option_env!("KERNEL_PUBLIC_KEY");
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    InvalidPublicKey,
+    MissingSignature,
+    InvalidSignature,
+    VerificationFailed,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureError::InvalidPublicKey => write!(f, "embedded public key is invalid"),
+            SignatureError::MissingSignature => write!(f, "signature file not found"),
+            SignatureError::InvalidSignature => write!(f, "signature is not 64 bytes"),
+            SignatureError::VerificationFailed => write!(f, "signature does not match"),
+        }
+    }
+}
+
+pub fn has_public_key() -> bool {
+    PUBLIC_KEY.is_some()
+}
+
+//公開鍵が無ければ何もしない
+//signatureはカーネルに対する64バイトの署名(kernel.elf.sig)
+pub fn verify(message: &[u8], signature: Option<&[u8]>) -> Result<(), SignatureError> {
+    let public_key = match PUBLIC_KEY {
+        Some(public_key) => parse_public_key(public_key).ok_or(SignatureError::InvalidPublicKey)?,
+        None => return Ok(()),
+    };
+    let signature = signature.ok_or(SignatureError::MissingSignature)?;
+    let signature =
+        Signature::from_slice(signature).map_err(|_| SignatureError::InvalidSignature)?;
+    public_key
+        .verify(message, &signature)
+        .map_err(|_| SignatureError::VerificationFailed)
+}
+
+fn parse_public_key(hex: &str) -> Option<PublicKey> {
+    let hex = hex.trim();
+    if hex.len() != PublicKey::BYTES * 2 {
+        return None;
+    }
+    let mut bytes = [0; PublicKey::BYTES];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+    PublicKey::from_slice(&bytes).ok()
+}
@@ -0,0 +1,60 @@
+use core::slice;
+
+//Root System Description Pointer
+//ACPI 1.0では先頭20バイトだけ、2.0以降はlengthまでが有効
+#[derive(Debug, Copy, Clone)]
+#[repr(C, packed)]
+pub struct Rsdp {
+    pub signature: [u8; 8],
+    pub checksum: u8,
+    pub oem_id: [u8; 6],
+    pub revision: u8,
+    pub rsdt_address: u32,
+    pub length: u32,
+    pub xsdt_address: u64,
+    pub extended_checksum: u8,
+    pub reserved: [u8; 3],
+}
+
+impl Rsdp {
+    pub const SIGNATURE: [u8; 8] = *b"RSD PTR ";
+    const V1_LENGTH: usize = 20;
+
+    //安全性は物理アドレスがそのまま読めることに依存
+    pub unsafe fn from_address(address: u64) -> Option<&'static Rsdp> {
+        if address == 0 {
+            return None;
+        }
+        let rsdp = &*(address as *const Rsdp);
+        if rsdp.is_valid() {
+            Some(rsdp)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.signature != Rsdp::SIGNATURE || !self.sum_is_zero(Rsdp::V1_LENGTH) {
+            return false;
+        }
+        if self.revision < 2 {
+            return true;
+        }
+        let length = self.length as usize;
+        length >= core::mem::size_of::<Rsdp>() && self.sum_is_zero(length)
+    }
+
+    //ACPI 2.0以降ならXSDT、それより前ならRSDTの物理アドレス
+    pub fn root_table_address(&self) -> u64 {
+        if self.revision >= 2 && self.xsdt_address != 0 {
+            self.xsdt_address
+        } else {
+            self.rsdt_address as u64
+        }
+    }
+
+    fn sum_is_zero(&self, length: usize) -> bool {
+        let bytes = unsafe { slice::from_raw_parts(self as *const Rsdp as *const u8, length) };
+        bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) == 0
+    }
+}
@@ -0,0 +1,121 @@
+use crate::arg::CommandLineTag;
+use crate::graphic::PixelColor;
+use core::slice;
+use core::str;
+
+//空白区切りでkey=valueかフラグが並ぶ
+//例: "loglevel=debug fg=ffffff bg=000000 nopci"
+#[derive(Copy, Clone)]
+pub struct CommandLine<'a> {
+    text: &'a str,
+}
+
+impl<'a> CommandLine<'a> {
+    pub fn new(text: &'a str) -> CommandLine<'a> {
+        CommandLine { text }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = Token<'a>> {
+        self.text
+            .split_whitespace()
+            .map(|token| match token.find('=') {
+                Some(equals) => Token::KeyValue(&token[..equals], &token[equals + 1..]),
+                None => Token::Flag(token),
+            })
+    }
+
+    //同じkeyが複数あれば後ろを優先する
+    pub fn value(&self, key: &str) -> Option<&'a str> {
+        self.tokens()
+            .filter_map(|token| match token {
+                Token::KeyValue(k, v) if k == key => Some(v),
+                _ => None,
+            })
+            .last()
+    }
+
+    pub fn flag(&self, key: &str) -> bool {
+        self.tokens().any(|token| token == Token::Flag(key))
+    }
+}
+
+impl CommandLine<'static> {
+    pub fn from_tag(command_line_tag: &CommandLineTag) -> CommandLine<'static> {
+        //安全性はブートローダに依存
+        let bytes = unsafe { slice::from_raw_parts(command_line_tag.data, command_line_tag.len) };
+        //UTF-8でなければ無視する
+        CommandLine::new(str::from_utf8(bytes).unwrap_or(""))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    Flag(&'a str),
+    KeyValue(&'a str, &'a str),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    fn parse(level: &str) -> Option<LogLevel> {
+        match level {
+            "error" => Some(LogLevel::Error),
+            "warn" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            _ => None,
+        }
+    }
+}
+
+//コマンドラインで切り替えられるカーネルの設定
+//知らない項目や不正な値は無視してデフォルトを使う
+#[derive(Debug, Copy, Clone)]
+pub struct Options {
+    pub log_level: LogLevel,
+    pub foreground: PixelColor,
+    pub background: PixelColor,
+    pub skip_pci_scan: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            log_level: LogLevel::Info,
+            foreground: PixelColor::FRONTGROUND,
+            background: PixelColor::BACKGROUND,
+            skip_pci_scan: false,
+        }
+    }
+}
+
+impl Options {
+    pub fn parse(command_line: &CommandLine) -> Options {
+        let default = Options::default();
+        Options {
+            log_level: command_line
+                .value("loglevel")
+                .and_then(LogLevel::parse)
+                .unwrap_or(default.log_level),
+            foreground: command_line
+                .value("fg")
+                .and_then(PixelColor::from_hex)
+                .unwrap_or(default.foreground),
+            background: command_line
+                .value("bg")
+                .and_then(PixelColor::from_hex)
+                .unwrap_or(default.background),
+            skip_pci_scan: command_line.flag("nopci"),
+        }
+    }
+}
@@ -2,7 +2,10 @@ use crate::font::{Font, FontWriter};
 use core::fmt;
 
 pub struct ConsoleWriter {
-    screen: [[char; ConsoleWriter::MAX_ROWS]; ConsoleWriter::MAX_COLUMNS],
+    //Font::MAX以上はerror_characterで書く
+    screen: [[u8; ConsoleWriter::MAX_ROWS]; ConsoleWriter::MAX_COLUMNS],
+    rows: usize,
+    columns: usize,
     cursor_row: usize,
     cursor_column: usize,
     characters: [Font; Font::MAX],
@@ -11,16 +14,20 @@ pub struct ConsoleWriter {
 }
 
 impl ConsoleWriter {
-    const MAX_ROWS: usize = 25;
-    const MAX_COLUMNS: usize = 80;
+    //アロケータがないので2560x1600までの大きさで確保しておく
+    //それより大きい画面では左上だけを使う
+    const MAX_ROWS: usize = 100;
+    const MAX_COLUMNS: usize = 320;
 
     pub fn new(writer: FontWriter) -> ConsoleWriter {
-        let screen = [['\0'; ConsoleWriter::MAX_ROWS]; ConsoleWriter::MAX_COLUMNS];
+        let screen = [[0; ConsoleWriter::MAX_ROWS]; ConsoleWriter::MAX_COLUMNS];
         let characters = Font::all();
         let error_character = Font::new('■');
 
         ConsoleWriter {
             screen: screen,
+            rows: writer.rows().min(ConsoleWriter::MAX_ROWS).max(1),
+            columns: writer.columns().min(ConsoleWriter::MAX_COLUMNS).max(1),
             cursor_row: 0,
             cursor_column: 0,
             characters: characters,
@@ -39,43 +46,43 @@ impl ConsoleWriter {
         match c {
             '\n' => self.new_line(),
             _ => {
-                if self.cursor_column >= ConsoleWriter::MAX_COLUMNS {
+                if self.cursor_column >= self.columns {
                     self.new_line();
                 }
-                self.screen[self.cursor_column][self.cursor_row] = c;
                 //へんなキャストだけど他にいい方法を知らない
                 let code = c as u32 as usize;
-                //範囲エラーが怖いのでget
-                let font = self.characters.get(code).unwrap_or(&self.error_character);
-                self.writer.write(self.cursor_column, self.cursor_row, font);
+                self.screen[self.cursor_column][self.cursor_row] = code.min(Font::MAX) as u8;
+                self.draw(self.cursor_column, self.cursor_row);
                 self.cursor_column += 1;
             }
         }
     }
 
+    fn draw(&self, x: usize, y: usize) {
+        let code = self.screen[x][y] as usize;
+        //範囲エラーが怖いのでget
+        let font = self.characters.get(code).unwrap_or(&self.error_character);
+        self.writer.write(x, y, font);
+    }
+
     fn new_line(&mut self) {
         self.cursor_column = 0;
-        if self.cursor_row < ConsoleWriter::MAX_ROWS - 1 {
+        if self.cursor_row < self.rows - 1 {
             self.cursor_row += 1;
         } else {
-            for x in 0..ConsoleWriter::MAX_COLUMNS {
-                for y in 0..ConsoleWriter::MAX_ROWS {
+            for x in 0..self.columns {
+                for y in 0..self.rows {
                     self.writer.clear(x, y);
                 }
             }
-            for y in 0..(ConsoleWriter::MAX_ROWS - 1) {
-                for x in 0..ConsoleWriter::MAX_COLUMNS {
+            for y in 0..(self.rows - 1) {
+                for x in 0..self.columns {
                     self.screen[x][y] = self.screen[x][y + 1];
-                    let character = self.screen[x][y];
-                    //へんなキャストだけど他にいい方法を知らない
-                    let code = character as u32 as usize;
-                    //範囲エラーが怖いのでget
-                    let font = self.characters.get(code).unwrap_or(&self.error_character);
-                    self.writer.write(x, y, font);
+                    self.draw(x, y);
                 }
             }
-            for x in 0..ConsoleWriter::MAX_COLUMNS {
-                self.screen[x][ConsoleWriter::MAX_ROWS - 1] = '\0';
+            for x in 0..self.columns {
+                self.screen[x][self.rows - 1] = 0;
             }
         }
     }
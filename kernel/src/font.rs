@@ -1,21 +1,37 @@
 use crate::graphic::{PixelColor, PixelWriter};
+use shinonome::Glyph;
 
 pub struct FontWriter {
     writer: PixelWriter,
+    foreground: PixelColor,
+    background: PixelColor,
 }
 
 impl FontWriter {
-    pub fn new(writer: PixelWriter) -> FontWriter {
-        FontWriter { writer: writer }
+    pub fn new(writer: PixelWriter, foreground: PixelColor, background: PixelColor) -> FontWriter {
+        FontWriter {
+            writer: writer,
+            foreground: foreground,
+            background: background,
+        }
+    }
+
+    //画面に収まる文字数
+    pub fn columns(&self) -> usize {
+        self.writer.horizontal_resolution() / Font::WIDTH
+    }
+
+    pub fn rows(&self) -> usize {
+        self.writer.vertical_resolution() / Font::HEIGHT
     }
 
     pub fn write(&self, x: usize, y: usize, font: &Font) {
-        let x = (Font::WIDTH * x) % self.writer.vertical_resolution();
-        let y = (Font::HEIGHT * y) % self.writer.horizontal_resolution();
+        let x = (Font::WIDTH * x) % self.writer.horizontal_resolution();
+        let y = (Font::HEIGHT * y) % self.writer.vertical_resolution();
         for dy in 0..Font::HEIGHT {
             for dx in 0..Font::WIDTH {
                 if font.is_draw_bit(dx, dy) {
-                    match self.writer.write(x + dx, y + dy, PixelColor::FRONTGROUND) {
+                    match self.writer.write(x + dx, y + dy, self.foreground) {
                         Ok(_) => (),
                         Err(_) => (),
                     };
@@ -25,11 +41,11 @@ impl FontWriter {
     }
 
     pub fn clear(&self, x: usize, y: usize) {
-        let x = (Font::WIDTH * x) % self.writer.vertical_resolution();
-        let y = (Font::HEIGHT * y) % self.writer.horizontal_resolution();
+        let x = (Font::WIDTH * x) % self.writer.horizontal_resolution();
+        let y = (Font::HEIGHT * y) % self.writer.vertical_resolution();
         for dy in 0..Font::HEIGHT {
             for dx in 0..Font::WIDTH {
-                match self.writer.write(x + dx, y + dy, PixelColor::BACKGROUND) {
+                match self.writer.write(x + dx, y + dy, self.background) {
                     Ok(_) => (),
                     Err(_) => (),
                 };
@@ -38,1062 +54,33 @@ impl FontWriter {
     }
 }
 
-//東雲フォント
+//東雲フォント、字形はブートローダと共有している
 #[derive(Debug, Copy, Clone)]
 pub struct Font {
-    //glyph:字形
-    glyph: [u8; Font::HEIGHT],
+    glyph: Glyph,
 }
 
 impl Font {
-    pub const MAX: usize = 0x80;
-    pub const HEIGHT: usize = 16;
-    pub const WIDTH: usize = 8;
+    pub const MAX: usize = shinonome::MAX;
+    pub const HEIGHT: usize = shinonome::HEIGHT;
+    pub const WIDTH: usize = shinonome::WIDTH;
 
     pub fn is_draw_bit(&self, x: usize, y: usize) -> bool {
-        //範囲エラーを避けたいのでget
-        ((self.glyph.get(y).unwrap_or(&0) << x) & 0b1000_0000) == 0b1000_0000
+        shinonome::is_draw_bit(&self.glyph, x, y)
     }
 
     pub fn new(c: char) -> Font {
-        match c {
-            '\x00' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x01' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00111000, 0b00111000, 0b01111100, 0b01111100,
-                    0b11111110, 0b11111110, 0b01111100, 0b01111100, 0b00111000, 0b00111000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x02' => Font {
-                glyph: [
-                    0b01010101, 0b10101010, 0b01010101, 0b10101010, 0b01010101, 0b10101010,
-                    0b01010101, 0b10101010, 0b01010101, 0b10101010, 0b01010101, 0b10101010,
-                    0b01010101, 0b10101010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x03' => Font {
-                glyph: [
-                    0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b11111000, 0b10001000,
-                    0b10001000, 0b10001000, 0b00000000, 0b00111110, 0b00001000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00001000,
-                ],
-            },
-            '\x04' => Font {
-                glyph: [
-                    0b00000000, 0b11111000, 0b10000000, 0b10000000, 0b11110000, 0b10000000,
-                    0b10000000, 0b10000000, 0b00111110, 0b00100000, 0b00100000, 0b00111100,
-                    0b00100000, 0b00100000, 0b00100000, 0b00000000,
-                ],
-            },
-            '\x05' => Font {
-                glyph: [
-                    0b00000000, 0b01110000, 0b10001000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10001000, 0b01110000, 0b00000000, 0b00111100, 0b00100010, 0b00100010,
-                    0b00111100, 0b00101000, 0b00100100, 0b00100010,
-                ],
-            },
-            '\x06' => Font {
-                glyph: [
-                    0b00000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b11111000, 0b00000000, 0b00111110, 0b00100000, 0b00100000,
-                    0b00111100, 0b00100000, 0b00100000, 0b00100000,
-                ],
-            },
-            '\x07' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b01000100,
-                    0b00111000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x08' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b11111110, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00000000,
-                    0b11111110, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x09' => Font {
-                glyph: [
-                    0b00000000, 0b10000100, 0b11000100, 0b10100100, 0b10100100, 0b10010100,
-                    0b10010100, 0b10001100, 0b10000100, 0b00100000, 0b00100000, 0b00100000,
-                    0b00100000, 0b00100000, 0b00100000, 0b00111110,
-                ],
-            },
-            '\x0a' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b01010000,
-                    0b01010000, 0b00100000, 0b00000000, 0b00111110, 0b00001000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00000000,
-                ],
-            },
-            '\x0b' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b11110000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x0c' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b11110000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x0d' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00011111, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x0e' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00011111, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x0f' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b11111111, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x10' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b11111111, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x11' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x12' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b11111111, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x13' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x14' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b11111111, 0b00000000,
-                ],
-            },
-            '\x15' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00011111, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x16' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b11110000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x17' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b11111111, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x18' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b11111111, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x19' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x1a' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000010, 0b00001100, 0b00110000, 0b11000000,
-                    0b00110000, 0b00001100, 0b00000010, 0b00000000, 0b11111110, 0b00000000,
-                    0b11111110, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x1b' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b10000000, 0b01100000, 0b00011000,
-                    0b00000110, 0b00011000, 0b01100000, 0b10000000, 0b11111110, 0b00000000,
-                    0b11111110, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x1c' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b11111110, 0b00100100, 0b00100100, 0b00100100, 0b00100100,
-                    0b01000100, 0b10000100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x1d' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000010, 0b00000100,
-                    0b00001000, 0b11111110, 0b00010000, 0b11111110, 0b00100000, 0b01000000,
-                    0b10000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x1e' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00001100, 0b00010010,
-                    0b00010000, 0b00010000, 0b00010000, 0b01111100, 0b00010000, 0b00010000,
-                    0b00111100, 0b01010010, 0b00100000, 0b00000000,
-                ],
-            },
-            '\x1f' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00010000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x20' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x21' => Font {
-                glyph: [
-                    0b00000000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00000000,
-                    0b00000000, 0b00010000, 0b00010000, 0b00000000,
-                ],
-            },
-            '\x22' => Font {
-                glyph: [
-                    0b01101100, 0b00100100, 0b00100100, 0b01001000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x23' => Font {
-                glyph: [
-                    0b00000000, 0b00010010, 0b00010010, 0b00010010, 0b01111111, 0b00100100,
-                    0b00100100, 0b00100100, 0b00100100, 0b00100100, 0b11111110, 0b01001000,
-                    0b01001000, 0b01001000, 0b01001000, 0b00000000,
-                ],
-            },
-            '\x24' => Font {
-                glyph: [
-                    0b00010000, 0b00111000, 0b01010100, 0b10010010, 0b10010010, 0b10010000,
-                    0b01010000, 0b00111000, 0b00010100, 0b00010010, 0b10010010, 0b10010010,
-                    0b01010100, 0b00111000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x25' => Font {
-                glyph: [
-                    0b00000001, 0b01100001, 0b10010010, 0b10010010, 0b10010100, 0b10010100,
-                    0b01101000, 0b00001000, 0b00010000, 0b00010110, 0b00101001, 0b00101001,
-                    0b01001001, 0b01001001, 0b10000110, 0b10000000,
-                ],
-            },
-            '\x26' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b01000100, 0b00101000,
-                    0b00010000, 0b00110000, 0b01001010, 0b10001010, 0b10000100, 0b10000100,
-                    0b01001010, 0b00110001, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x27' => Font {
-                glyph: [
-                    0b01100000, 0b00100000, 0b00100000, 0b01000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x28' => Font {
-                glyph: [
-                    0b00000000, 0b00000010, 0b00000100, 0b00001000, 0b00001000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00001000, 0b00001000, 0b00000100, 0b00000010,
-                ],
-            },
-            '\x29' => Font {
-                glyph: [
-                    0b00000000, 0b01000000, 0b00100000, 0b00010000, 0b00010000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
-                    0b00010000, 0b00010000, 0b00100000, 0b01000000,
-                ],
-            },
-            '\x2a' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00010000, 0b10010010,
-                    0b01010100, 0b00111000, 0b01010100, 0b10010010, 0b00010000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x2b' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b11111110, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x2c' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b01100000, 0b00100000, 0b00100000, 0b01000000,
-                ],
-            },
-            '\x2d' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b11111110, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x2e' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b01100000, 0b01100000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x2f' => Font {
-                glyph: [
-                    0b00000000, 0b00000010, 0b00000010, 0b00000100, 0b00000100, 0b00001000,
-                    0b00001000, 0b00010000, 0b00010000, 0b00100000, 0b00100000, 0b01000000,
-                    0b01000000, 0b10000000, 0b10000000, 0b00000000,
-                ],
-            },
-            '\x30' => Font {
-                glyph: [
-                    0b00000000, 0b00011000, 0b00100100, 0b00100100, 0b01000010, 0b01000010,
-                    0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b00100100,
-                    0b00100100, 0b00011000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x31' => Font {
-                glyph: [
-                    0b00000000, 0b00010000, 0b00010000, 0b00110000, 0b01010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x32' => Font {
-                glyph: [
-                    0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01000010, 0b00000010,
-                    0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b00100000, 0b01000000,
-                    0b01000000, 0b01111110, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x33' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b00000010,
-                    0b00000100, 0b00111000, 0b00000100, 0b00000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x34' => Font {
-                glyph: [
-                    0b00000000, 0b00001000, 0b00011000, 0b00011000, 0b00101000, 0b00101000,
-                    0b01001000, 0b01001000, 0b10001000, 0b11111110, 0b00001000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x35' => Font {
-                glyph: [
-                    0b00000000, 0b01111100, 0b01000000, 0b01000000, 0b01000000, 0b10111000,
-                    0b11000100, 0b10000010, 0b00000010, 0b00000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x36' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b01000000, 0b10000000, 0b10000000,
-                    0b10111000, 0b11000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x37' => Font {
-                glyph: [
-                    0b00000000, 0b11111110, 0b00000010, 0b00000100, 0b00000100, 0b00001000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00000000,
-                ],
-            },
-            '\x38' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x39' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000010, 0b01000110, 0b00111010, 0b00000010, 0b00000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3a' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000,
-                    0b00011000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3b' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00001000,
-                    0b00001000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3c' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000010, 0b00000100, 0b00001000,
-                    0b00010000, 0b00100000, 0b00100000, 0b00010000, 0b00001000, 0b00000100,
-                    0b00000010, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3d' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111110,
-                    0b00000000, 0b00000000, 0b00000000, 0b11111110, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3e' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b01000000, 0b00100000, 0b00010000,
-                    0b00001000, 0b00000100, 0b00000100, 0b00001000, 0b00010000, 0b00100000,
-                    0b01000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x3f' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b00000100, 0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00000000,
-                    0b00000000, 0b00010000, 0b00010000, 0b00000000,
-                ],
-            },
-            '\x40' => Font {
-                glyph: [
-                    0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01011010, 0b10110101,
-                    0b10100101, 0b10100101, 0b10100101, 0b10011010, 0b01000000, 0b01000000,
-                    0b00100010, 0b00011100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x41' => Font {
-                glyph: [
-                    0b00000000, 0b00010000, 0b00010000, 0b00101000, 0b00101000, 0b00101000,
-                    0b01000100, 0b01000100, 0b01000100, 0b01111100, 0b10000010, 0b10000010,
-                    0b10000010, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x42' => Font {
-                glyph: [
-                    0b00000000, 0b11110000, 0b10001000, 0b10000100, 0b10000100, 0b10000100,
-                    0b10001000, 0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000100, 0b11111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x43' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b01000010, 0b10000000, 0b10000000,
-                    0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b01000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x44' => Font {
-                glyph: [
-                    0b00000000, 0b11110000, 0b10001000, 0b10000100, 0b10000100, 0b10000010,
-                    0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000100, 0b10000100,
-                    0b10001000, 0b11110000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x45' => Font {
-                glyph: [
-                    0b00000000, 0b11111110, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b11111100, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b11111110, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x46' => Font {
-                glyph: [
-                    0b00000000, 0b11111110, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b11111100, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b10000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x47' => Font {
-                glyph: [
-                    0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01000000, 0b10000000,
-                    0b10000000, 0b10001110, 0b10000010, 0b10000010, 0b10000010, 0b01000010,
-                    0b01100110, 0b00011010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x48' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000010, 0b11111110, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000010, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x49' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4a' => Font {
-                glyph: [
-                    0b00000000, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00000010,
-                    0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b01000010,
-                    0b00100100, 0b00011000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4b' => Font {
-                glyph: [
-                    0b00000000, 0b01000010, 0b01000010, 0b01000100, 0b01000100, 0b01001000,
-                    0b01011000, 0b01101000, 0b01100100, 0b01000100, 0b01000010, 0b01000010,
-                    0b01000001, 0b01000001, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4c' => Font {
-                glyph: [
-                    0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000,
-                    0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000,
-                    0b01000000, 0b01111110, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4d' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b10000010, 0b11000110, 0b11000110, 0b11000110,
-                    0b10101010, 0b10101010, 0b10101010, 0b10010010, 0b10010010, 0b10010010,
-                    0b10010010, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4e' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b11000010, 0b11000010, 0b10100010, 0b10100010,
-                    0b10010010, 0b10010010, 0b10010010, 0b10001010, 0b10001010, 0b10000110,
-                    0b10000110, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x4f' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b10000010, 0b10000010,
-                    0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x50' => Font {
-                glyph: [
-                    0b00000000, 0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000100, 0b11111000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
-                    0b10000000, 0b10000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x51' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b10000010, 0b10000010,
-                    0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10111010, 0b01000100,
-                    0b01000100, 0b00111000, 0b00001000, 0b00000110,
-                ],
-            },
-            '\x52' => Font {
-                glyph: [
-                    0b00000000, 0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000100, 0b11111000, 0b10001000, 0b10000100, 0b10000100, 0b10000100,
-                    0b10000010, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x53' => Font {
-                glyph: [
-                    0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000000,
-                    0b01100000, 0b00011000, 0b00000100, 0b00000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x54' => Font {
-                glyph: [
-                    0b00000000, 0b11111110, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x55' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x56' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100,
-                    0b01000100, 0b01000100, 0b00101000, 0b00101000, 0b00101000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x57' => Font {
-                glyph: [
-                    0b00000000, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010,
-                    0b10010010, 0b10101010, 0b10101010, 0b01101100, 0b01000100, 0b01000100,
-                    0b01000100, 0b01000100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x58' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b01000100, 0b01000100, 0b00101000, 0b00101000,
-                    0b00010000, 0b00101000, 0b00101000, 0b00101000, 0b01000100, 0b01000100,
-                    0b10000010, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x59' => Font {
-                glyph: [
-                    0b00000000, 0b10000010, 0b10000010, 0b01000100, 0b01000100, 0b01000100,
-                    0b00101000, 0b00101000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x5a' => Font {
-                glyph: [
-                    0b00000000, 0b11111110, 0b00000100, 0b00000100, 0b00001000, 0b00001000,
-                    0b00010000, 0b00010000, 0b00100000, 0b00100000, 0b01000000, 0b01000000,
-                    0b10000000, 0b11111110, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x5b' => Font {
-                glyph: [
-                    0b00011110, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00011110,
-                ],
-            },
-            '\x5c' => Font {
-                glyph: [
-                    0b00000000, 0b10000000, 0b10000000, 0b01000000, 0b01000000, 0b00100000,
-                    0b00100000, 0b00010000, 0b00010000, 0b00001000, 0b00001000, 0b00000100,
-                    0b00000100, 0b00000010, 0b00000010, 0b00000000,
-                ],
-            },
-            '\x5d' => Font {
-                glyph: [
-                    0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b11110000,
-                ],
-            },
-            '\x5e' => Font {
-                glyph: [
-                    0b00010000, 0b00101000, 0b01000100, 0b10000010, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x5f' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b11111110, 0b00000000,
-                ],
-            },
-            '\x60' => Font {
-                glyph: [
-                    0b00110000, 0b00100000, 0b00100000, 0b00010000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x61' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111100,
-                    0b01000010, 0b00000010, 0b00111110, 0b01000010, 0b10000010, 0b10000010,
-                    0b10000110, 0b01111010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x62' => Font {
-                glyph: [
-                    0b00000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10111000,
-                    0b11000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b11000100, 0b10111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x63' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000,
-                    0b01000100, 0b10000010, 0b10000000, 0b10000000, 0b10000000, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x64' => Font {
-                glyph: [
-                    0b00000000, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00111010,
-                    0b01000110, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000110, 0b00111010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x65' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000,
-                    0b01000100, 0b10000010, 0b10000010, 0b11111110, 0b10000000, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x66' => Font {
-                glyph: [
-                    0b00000000, 0b00001100, 0b00010000, 0b00010000, 0b00010000, 0b01111100,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x67' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111011,
-                    0b01000100, 0b01000100, 0b01000100, 0b00111000, 0b01000000, 0b01111000,
-                    0b10000100, 0b10000010, 0b10000010, 0b01111100,
-                ],
-            },
-            '\x68' => Font {
-                glyph: [
-                    0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01011100,
-                    0b01100010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010,
-                    0b01000010, 0b01000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x69' => Font {
-                glyph: [
-                    0b00000000, 0b00010000, 0b00010000, 0b00000000, 0b00000000, 0b00110000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x6a' => Font {
-                glyph: [
-                    0b00000000, 0b00001000, 0b00001000, 0b00000000, 0b00000000, 0b00011000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00010000, 0b01100000,
-                ],
-            },
-            '\x6b' => Font {
-                glyph: [
-                    0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000010,
-                    0b01000100, 0b01001000, 0b01010000, 0b01101000, 0b01000100, 0b01000100,
-                    0b01000010, 0b01000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x6c' => Font {
-                glyph: [
-                    0b00000000, 0b00110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x6d' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11101100,
-                    0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010,
-                    0b10010010, 0b10010010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x6e' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01011100,
-                    0b01100010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010,
-                    0b01000010, 0b01000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x6f' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000,
-                    0b01000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
-                    0b01000100, 0b00111000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x70' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10111000,
-                    0b11000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b11000100,
-                    0b10111000, 0b10000000, 0b10000000, 0b10000000,
-                ],
-            },
-            '\x71' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111010,
-                    0b01000110, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000110,
-                    0b00111010, 0b00000010, 0b00000010, 0b00000010,
-                ],
-            },
-            '\x72' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00101100,
-                    0b00110000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
-                    0b00100000, 0b00100000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x73' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111100,
-                    0b01000010, 0b01000000, 0b01100000, 0b00011000, 0b00000110, 0b00000010,
-                    0b01000010, 0b00111100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x74' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000, 0b01111100,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00001100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x75' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01000010,
-                    0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010,
-                    0b01000110, 0b00111010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x76' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010,
-                    0b10000010, 0b10000010, 0b01000100, 0b01000100, 0b00101000, 0b00101000,
-                    0b00010000, 0b00010000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x77' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10010010,
-                    0b10010010, 0b10010010, 0b10010010, 0b10101010, 0b10101010, 0b01000100,
-                    0b01000100, 0b01000100, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x78' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010,
-                    0b01000100, 0b00101000, 0b00101000, 0b00010000, 0b00101000, 0b00101000,
-                    0b01000100, 0b10000010, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x79' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010,
-                    0b10000010, 0b01000100, 0b01000100, 0b00101000, 0b00101000, 0b00011000,
-                    0b00010000, 0b00010000, 0b00100000, 0b11000000,
-                ],
-            },
-            '\x7a' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01111110,
-                    0b00000100, 0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00100000,
-                    0b01000000, 0b11111110, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x7b' => Font {
-                glyph: [
-                    0b00000100, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
-                    0b00001000, 0b00010000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
-                    0b00001000, 0b00001000, 0b00001000, 0b00000100,
-                ],
-            },
-            '\x7c' => Font {
-                glyph: [
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                    0b00010000, 0b00010000, 0b00010000, 0b00010000,
-                ],
-            },
-            '\x7d' => Font {
-                glyph: [
-                    0b01000000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
-                    0b00100000, 0b00010000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
-                    0b00100000, 0b00100000, 0b00100000, 0b01000000,
-                ],
-            },
-            '\x7e' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b10010010, 0b00001100,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
-            '\x7f' => Font {
-                glyph: [
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                    0b00000000, 0b00000000, 0b00000000, 0b00000000,
-                ],
-            },
+        Font {
             //非対応文字は■
-            _ => Font {
-                glyph: [
-                    0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111,
-                    0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111,
-                    0b11111111, 0b11111111, 0b11111111, 0b11111111,
-                ],
-            },
+            glyph: *shinonome::glyph(c).unwrap_or(&shinonome::UNSUPPORTED),
         }
     }
 
     pub fn all() -> [Font; Font::MAX] {
-        [
-            Font::new('\x00'),
-            Font::new('\x01'),
-            Font::new('\x02'),
-            Font::new('\x03'),
-            Font::new('\x04'),
-            Font::new('\x05'),
-            Font::new('\x06'),
-            Font::new('\x07'),
-            Font::new('\x08'),
-            Font::new('\x09'),
-            Font::new('\x0a'),
-            Font::new('\x0b'),
-            Font::new('\x0c'),
-            Font::new('\x0d'),
-            Font::new('\x0e'),
-            Font::new('\x0f'),
-            Font::new('\x10'),
-            Font::new('\x11'),
-            Font::new('\x12'),
-            Font::new('\x13'),
-            Font::new('\x14'),
-            Font::new('\x15'),
-            Font::new('\x16'),
-            Font::new('\x17'),
-            Font::new('\x18'),
-            Font::new('\x19'),
-            Font::new('\x1a'),
-            Font::new('\x1b'),
-            Font::new('\x1c'),
-            Font::new('\x1d'),
-            Font::new('\x1e'),
-            Font::new('\x1f'),
-            Font::new('\x20'),
-            Font::new('\x21'),
-            Font::new('\x22'),
-            Font::new('\x23'),
-            Font::new('\x24'),
-            Font::new('\x25'),
-            Font::new('\x26'),
-            Font::new('\x27'),
-            Font::new('\x28'),
-            Font::new('\x29'),
-            Font::new('\x2a'),
-            Font::new('\x2b'),
-            Font::new('\x2c'),
-            Font::new('\x2d'),
-            Font::new('\x2e'),
-            Font::new('\x2f'),
-            Font::new('\x30'),
-            Font::new('\x31'),
-            Font::new('\x32'),
-            Font::new('\x33'),
-            Font::new('\x34'),
-            Font::new('\x35'),
-            Font::new('\x36'),
-            Font::new('\x37'),
-            Font::new('\x38'),
-            Font::new('\x39'),
-            Font::new('\x3a'),
-            Font::new('\x3b'),
-            Font::new('\x3c'),
-            Font::new('\x3d'),
-            Font::new('\x3e'),
-            Font::new('\x3f'),
-            Font::new('\x40'),
-            Font::new('\x41'),
-            Font::new('\x42'),
-            Font::new('\x43'),
-            Font::new('\x44'),
-            Font::new('\x45'),
-            Font::new('\x46'),
-            Font::new('\x47'),
-            Font::new('\x48'),
-            Font::new('\x49'),
-            Font::new('\x4a'),
-            Font::new('\x4b'),
-            Font::new('\x4c'),
-            Font::new('\x4d'),
-            Font::new('\x4e'),
-            Font::new('\x4f'),
-            Font::new('\x50'),
-            Font::new('\x51'),
-            Font::new('\x52'),
-            Font::new('\x53'),
-            Font::new('\x54'),
-            Font::new('\x55'),
-            Font::new('\x56'),
-            Font::new('\x57'),
-            Font::new('\x58'),
-            Font::new('\x59'),
-            Font::new('\x5a'),
-            Font::new('\x5b'),
-            Font::new('\x5c'),
-            Font::new('\x5d'),
-            Font::new('\x5e'),
-            Font::new('\x5f'),
-            Font::new('\x60'),
-            Font::new('\x61'),
-            Font::new('\x62'),
-            Font::new('\x63'),
-            Font::new('\x64'),
-            Font::new('\x65'),
-            Font::new('\x66'),
-            Font::new('\x67'),
-            Font::new('\x68'),
-            Font::new('\x69'),
-            Font::new('\x6a'),
-            Font::new('\x6b'),
-            Font::new('\x6c'),
-            Font::new('\x6d'),
-            Font::new('\x6e'),
-            Font::new('\x6f'),
-            Font::new('\x70'),
-            Font::new('\x71'),
-            Font::new('\x72'),
-            Font::new('\x73'),
-            Font::new('\x74'),
-            Font::new('\x75'),
-            Font::new('\x76'),
-            Font::new('\x77'),
-            Font::new('\x78'),
-            Font::new('\x79'),
-            Font::new('\x7a'),
-            Font::new('\x7b'),
-            Font::new('\x7c'),
-            Font::new('\x7d'),
-            Font::new('\x7e'),
-            Font::new('\x7f'),
-        ]
+        let mut fonts = [Font::new('\0'); Font::MAX];
+        for (code, font) in fonts.iter_mut().enumerate() {
+            *font = Font::new(code as u8 as char);
+        }
+        fonts
     }
 }
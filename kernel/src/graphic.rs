@@ -1,10 +1,11 @@
-use crate::arg::{FrameBuffer, FrameBufferConfig};
+use crate::arg::{FrameBuffer, FrameBufferConfig, PixelFormat};
 use core::result::Result;
 
 #[derive(Debug, Copy, Clone)]
 pub enum PixelWriter {
     Rgb(RGBWriter),
     Bgr(BGRWriter),
+    Bitmask(BitmaskWriter),
 }
 
 impl PixelWriter {
@@ -22,10 +23,18 @@ impl PixelWriter {
         PixelWriter::Bgr(BGRWriter::new(frame_buffer, frame_buffer_config))
     }
 
+    pub fn new_bitmask(
+        frame_buffer: FrameBuffer,
+        frame_buffer_config: FrameBufferConfig,
+    ) -> PixelWriter {
+        PixelWriter::Bitmask(BitmaskWriter::new(frame_buffer, frame_buffer_config))
+    }
+
     pub fn vertical_resolution(&self) -> usize {
         match self {
             PixelWriter::Rgb(w) => w.vertical_resolution(),
             PixelWriter::Bgr(w) => w.vertical_resolution(),
+            PixelWriter::Bitmask(w) => w.vertical_resolution(),
         }
     }
 
@@ -33,6 +42,7 @@ impl PixelWriter {
         match self {
             PixelWriter::Rgb(w) => w.horizontal_resolution(),
             PixelWriter::Bgr(w) => w.horizontal_resolution(),
+            PixelWriter::Bitmask(w) => w.horizontal_resolution(),
         }
     }
 
@@ -40,6 +50,7 @@ impl PixelWriter {
         match self {
             PixelWriter::Rgb(w) => w.write(x, y, c),
             PixelWriter::Bgr(w) => w.write(x, y, c),
+            PixelWriter::Bitmask(w) => w.write(x, y, c),
         }
     }
 }
@@ -133,6 +144,93 @@ impl BGRWriter {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct BitmaskWriter {
+    frame_buffer_base: *mut u8,
+    size: usize,
+    pixels_per_scan_line: usize,
+    vertical_resolution: usize,
+    horizontal_resolution: usize,
+    red: ChannelMask,
+    green: ChannelMask,
+    blue: ChannelMask,
+}
+
+impl BitmaskWriter {
+    pub fn new(frame_buffer: FrameBuffer, frame_buffer_config: FrameBufferConfig) -> BitmaskWriter {
+        //Bitmask以外で呼ばれたらマスクが無いので何も描けない
+        let (red, green, blue) = match frame_buffer_config.pixel_format {
+            PixelFormat::Bitmask { red, green, blue } => (red, green, blue),
+            _ => (0, 0, 0),
+        };
+        BitmaskWriter {
+            frame_buffer_base: frame_buffer.base,
+            size: frame_buffer.size,
+            pixels_per_scan_line: frame_buffer_config.pixels_per_scan_line,
+            vertical_resolution: frame_buffer_config.vertical_resolution,
+            horizontal_resolution: frame_buffer_config.horizontal_resolution,
+            red: ChannelMask::new(red),
+            green: ChannelMask::new(green),
+            blue: ChannelMask::new(blue),
+        }
+    }
+
+    pub fn vertical_resolution(&self) -> usize {
+        self.vertical_resolution
+    }
+
+    pub fn horizontal_resolution(&self) -> usize {
+        self.horizontal_resolution
+    }
+
+    pub fn write(&self, x: usize, y: usize, c: PixelColor) -> Result<(), &str> {
+        let point = 4 * (self.pixels_per_scan_line * y + x);
+        let pixel_out_of_range = point + 4 > self.size
+            || x >= self.horizontal_resolution
+            || y >= self.vertical_resolution;
+        if pixel_out_of_range {
+            Err("pixel out of range")
+        } else {
+            let pixel = self.red.pack(c.r) | self.green.pack(c.g) | self.blue.pack(c.b);
+            unsafe {
+                (self.frame_buffer_base.add(point) as *mut u32).write_volatile(pixel);
+            }
+            Ok(())
+        }
+    }
+}
+
+//マスクの位置と幅、例えば0x0000f800ならshift 11, width 5
+#[derive(Debug, Copy, Clone)]
+struct ChannelMask {
+    shift: u32,
+    width: u32,
+}
+
+impl ChannelMask {
+    fn new(mask: u32) -> ChannelMask {
+        if mask == 0 {
+            return ChannelMask { shift: 0, width: 0 };
+        }
+        ChannelMask {
+            shift: mask.trailing_zeros(),
+            width: mask.count_ones(),
+        }
+    }
+
+    //8bitの値をマスクの幅に合わせて詰める
+    fn pack(&self, value: u8) -> u32 {
+        let value = value as u32;
+        let scaled = match self.width {
+            0 => return 0,
+            width if width <= 8 => value >> (8 - width),
+            width => value << (width - 8),
+        };
+        scaled << self.shift
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct PixelColor {
     pub r: u8,
     pub g: u8,
@@ -150,4 +248,17 @@ impl PixelColor {
         g: 255,
         b: 255,
     };
+
+    //"2d76ed"のような16進数のRGB
+    pub fn from_hex(hex: &str) -> Option<PixelColor> {
+        if hex.len() != 6 {
+            return None;
+        }
+        let rgb = u32::from_str_radix(hex, 16).ok()?;
+        Some(PixelColor {
+            r: (rgb >> 16) as u8,
+            g: (rgb >> 8) as u8,
+            b: rgb as u8,
+        })
+    }
 }
@@ -1,8 +1,17 @@
 #![no_std]
+#![feature(abi_efiapi)]
 #![feature(asm)]
 
-pub mod arg;
+pub use arg;
+pub mod acpi;
+pub mod cmdline;
 pub mod console;
 pub mod font;
 pub mod graphic;
+pub mod memory_map;
+pub mod module;
+pub mod panic;
 pub mod pci;
+pub mod runtime;
+pub mod smbios;
+pub mod symbol;
@@ -1,34 +1,64 @@
 #![feature(abi_efiapi)]
+#![feature(asm)]
 #![no_std]
 #![no_main]
 
 use core::fmt::Write;
 use core::panic::PanicInfo;
-use kernel::arg::{Argument, PixelFormat};
+use kernel::acpi::Rsdp;
+use kernel::arg::{
+    self, AcpiTag, Argument, CommandLineTag, FrameBufferTag, KernelDigestTag, MemoryMapTag,
+    PagingTag, PixelFormat, RuntimeServicesTag, SmbiosTag, SymbolTableTag,
+};
+use kernel::cmdline::{CommandLine, LogLevel, Options};
 use kernel::console::ConsoleWriter;
 use kernel::font::FontWriter;
-use kernel::graphic::{PixelColor, PixelWriter};
+use kernel::graphic::PixelWriter;
+use kernel::memory_map::MemoryMap;
+use kernel::module;
+use kernel::panic;
 use kernel::pci::{Configuration, Pci};
+use kernel::runtime::RuntimeServices;
+use kernel::smbios::Smbios;
+use kernel::symbol::SymbolTable;
 
 #[panic_handler]
-fn panic(_info: &PanicInfo) -> ! {
-    loop {}
+fn panic_handler(info: &PanicInfo) -> ! {
+    panic::write(info);
+    halt()
 }
 
 #[no_mangle]
 pub extern "C" fn _start(args_ptr: *const Argument) -> ! {
-    let args = unsafe { *args_ptr };
-    let frame_buffer = args.frame_buffer;
-    let frame_buffer_config = args.frame_buffer_config;
+    //タグが後ろに続くのでコピーせず参照する
+    let args = unsafe { &*args_ptr };
+    //ブートローダとカーネルで形式が違うと何も信用できないので止まる
+    if !args.is_valid() {
+        halt();
+    }
+    let frame_buffer_tag = match args.find::<FrameBufferTag>() {
+        Some(frame_buffer_tag) => frame_buffer_tag,
+        None => halt(),
+    };
+    let command_line = match args.find::<CommandLineTag>() {
+        Some(command_line_tag) => CommandLine::from_tag(command_line_tag),
+        None => CommandLine::new(""),
+    };
+    let options = Options::parse(&command_line);
+
+    let frame_buffer = frame_buffer_tag.frame_buffer;
+    let frame_buffer_config = frame_buffer_tag.frame_buffer_config;
     let pixel_writer = match frame_buffer_config.pixel_format {
         PixelFormat::Rgb => PixelWriter::new_rgb(frame_buffer, frame_buffer_config),
         PixelFormat::Bgr => PixelWriter::new_bgr(frame_buffer, frame_buffer_config),
+        PixelFormat::Bitmask { .. } => PixelWriter::new_bitmask(frame_buffer, frame_buffer_config),
     };
+    panic::use_pixel_writer(pixel_writer, options.foreground, options.background);
 
     for x in 0..pixel_writer.horizontal_resolution() {
         for y in 0..pixel_writer.vertical_resolution() {
             //一応エラー処理、エラーはめんどうなので無視
-            match pixel_writer.write(x, y, PixelColor::BACKGROUND) {
+            match pixel_writer.write(x, y, options.background) {
                 Ok(_) => (),
                 Err(_) => (),
             };
@@ -36,29 +66,170 @@ pub extern "C" fn _start(args_ptr: *const Argument) -> ! {
     }
 
     //Consoleの依存をFontに集約したかったのでFontWriterを追加
-    let font_writer = FontWriter::new(pixel_writer);
+    let font_writer = FontWriter::new(pixel_writer, options.foreground, options.background);
     let mut console_writer = ConsoleWriter::new(font_writer);
     write!(console_writer, "Welcome to MikanOS\n").unwrap();
+    //どのビルドが動いているかわかるようにブートローダが計算したハッシュを出す
+    if let Some(kernel_digest_tag) = args.find::<KernelDigestTag>() {
+        write!(console_writer, "SHA-256 ").unwrap();
+        for byte in kernel_digest_tag.sha256.iter() {
+            write!(console_writer, "{:02x}", byte).unwrap();
+        }
+        write!(console_writer, "\n").unwrap();
+    }
+
+    if options.log_level >= LogLevel::Debug {
+        write!(console_writer, "Boot protocol version {}\n", arg::VERSION).unwrap();
+    }
+    let verbose = options.log_level >= LogLevel::Info;
+    if verbose && !command_line.as_str().is_empty() {
+        write!(console_writer, "Command line: {}\n", command_line.as_str()).unwrap();
+    }
+
+    if let Some(symbol_table_tag) = args.find::<SymbolTableTag>() {
+        let symbol_table = SymbolTable::new(symbol_table_tag.symbol_table);
+        //パニックしたときに呼び出し元を関数名で出す
+        panic::use_symbol_table(symbol_table);
+        if verbose {
+            write!(console_writer, "{} kernel symbols\n", symbol_table.len()).unwrap();
+        }
+    }
+
+    if let Some(memory_map_tag) = args.find::<MemoryMapTag>() {
+        let memory_map = MemoryMap::new(memory_map_tag.memory_map);
+        if verbose {
+            write!(
+                console_writer,
+                "{} MiB available memory\n",
+                memory_map.available_pages() * MemoryMap::PAGE_SIZE / 1024 / 1024
+            )
+            .unwrap();
+        }
+    }
+
+    if verbose {
+        for module in module::modules(args) {
+            write!(
+                console_writer,
+                "Module {}: {} bytes\n",
+                module.name(),
+                module.data().len()
+            )
+            .unwrap();
+        }
+    }
+
+    if verbose {
+        if let Some(paging_tag) = args.find::<PagingTag>() {
+            write!(
+                console_writer,
+                "Kernel at {:#x} (physical {:#x}), physical memory at {:#x}\n",
+                paging_tag.kernel_virtual_base,
+                paging_tag.kernel_physical_base,
+                paging_tag.physical_offset
+            )
+            .unwrap();
+        }
+    }
+
+    //低位はブートローダがidentity mapしている
+    match args
+        .find::<AcpiTag>()
+        .and_then(|acpi_tag| unsafe { Rsdp::from_address(acpi_tag.rsdp) })
+    {
+        Some(rsdp) => {
+            if verbose {
+                write!(
+                    console_writer,
+                    "ACPI revision {}, root table at {:#x}\n",
+                    rsdp.revision,
+                    rsdp.root_table_address()
+                )
+                .unwrap();
+            }
+        }
+        None => write!(console_writer, "No valid ACPI RSDP\n").unwrap(),
+    }
+
+    if verbose {
+        let runtime_services = args
+            .find::<RuntimeServicesTag>()
+            .and_then(|tag| unsafe { RuntimeServices::from_address(tag.runtime_services) });
+        if let Some(runtime_services) = runtime_services {
+            match runtime_services.get_time() {
+                Ok(time) => write!(console_writer, "Time: {}\n", time).unwrap(),
+                Err(status) => {
+                    write!(console_writer, "Failed to get time: {:#x}\n", status.0).unwrap()
+                }
+            }
+        }
+    }
 
-    let pci = Pci::new();
-    for device in pci.iter() {
-        let vender_id = Configuration::vender_id(&device);
-        let base_class = Configuration::base_class(&device);
-        let sub_class = Configuration::sub_class(&device);
-        let header_type = Configuration::header_type(&device);
-        write!(
-            console_writer,
-            "{}.{}.{}: vneder {:x}, base class {}, sub class {}, head {}\n",
-            device.bus(),
-            device.device(),
-            device.function(),
-            vender_id,
-            base_class,
-            sub_class,
-            header_type
-        )
-        .unwrap();
+    //低位はブートローダがidentity mapしている
+    if verbose {
+        if let Some(smbios) = args
+            .find::<SmbiosTag>()
+            .and_then(|smbios_tag| unsafe { Smbios::from_address(smbios_tag.entry_point) })
+        {
+            let (major, minor) = smbios.version();
+            write!(console_writer, "SMBIOS {}.{}\n", major, minor).unwrap();
+            if let Some(system) = smbios.system() {
+                write!(
+                    console_writer,
+                    "System: {} {}\n",
+                    system.manufacturer().unwrap_or("Unknown"),
+                    system.product_name().unwrap_or("Unknown")
+                )
+                .unwrap();
+            }
+            for memory_device in smbios.memory_devices() {
+                //空きスロットは出さない
+                if let Some(size) = memory_device.size_in_kib() {
+                    write!(
+                        console_writer,
+                        "Memory {}: {} MiB {}\n",
+                        memory_device.device_locator().unwrap_or("?"),
+                        size / 1024,
+                        memory_device.manufacturer().unwrap_or("")
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    if options.skip_pci_scan {
+        if verbose {
+            write!(console_writer, "PCI scan skipped\n").unwrap();
+        }
+    } else if verbose {
+        //今は表示するためだけに走査している
+        let pci = Pci::new();
+        for device in pci.iter() {
+            let vender_id = Configuration::vender_id(&device);
+            let base_class = Configuration::base_class(&device);
+            let sub_class = Configuration::sub_class(&device);
+            let header_type = Configuration::header_type(&device);
+            write!(
+                console_writer,
+                "{}.{}.{}: vneder {:x}, base class {}, sub class {}, head {}\n",
+                device.bus(),
+                device.device(),
+                device.function(),
+                vender_id,
+                base_class,
+                sub_class,
+                header_type
+            )
+            .unwrap();
+        }
     }
 
     loop {}
 }
+
+fn halt() -> ! {
+    loop {
+        unsafe { asm!("hlt") };
+    }
+}
@@ -0,0 +1,40 @@
+use crate::arg::{self, MemoryDescriptor, MemoryType};
+use core::slice;
+
+pub struct MemoryMap {
+    descriptors: &'static [MemoryDescriptor],
+}
+
+impl MemoryMap {
+    pub const PAGE_SIZE: u64 = 4096;
+
+    pub fn new(memory_map: arg::MemoryMap) -> MemoryMap {
+        //安全性はブートローダに依存
+        let descriptors = if memory_map.descriptors.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(memory_map.descriptors, memory_map.len) }
+        };
+        MemoryMap { descriptors }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MemoryDescriptor> {
+        self.descriptors.iter()
+    }
+
+    //カーネルが自由に使えるページ数
+    pub fn available_pages(&self) -> u64 {
+        self.iter()
+            .filter(|d| MemoryMap::is_available(d.memory_type))
+            .map(|d| d.page_count)
+            .sum()
+    }
+
+    //ブートサービスの領域はexit_boot_services後に解放されている
+    pub fn is_available(memory_type: MemoryType) -> bool {
+        matches!(
+            memory_type,
+            MemoryType::Conventional | MemoryType::BootServicesCode | MemoryType::BootServicesData
+        )
+    }
+}
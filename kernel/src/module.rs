@@ -0,0 +1,49 @@
+use crate::arg::{Argument, ModuleTag};
+use core::slice;
+use core::str;
+
+//ブートローダが読み込んだinitrdやモジュール
+//LOADER_DATAに置かれているので読み取り専用で使う
+#[derive(Copy, Clone)]
+pub struct Module {
+    name: &'static str,
+    data: &'static [u8],
+}
+
+impl Module {
+    pub fn from_tag(module_tag: &ModuleTag) -> Module {
+        //安全性はブートローダに依存
+        let name = if module_tag.name.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(module_tag.name, module_tag.name_len) }
+        };
+        let data = if module_tag.data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(module_tag.data, module_tag.len) }
+        };
+        Module {
+            //UTF-8でなければ名前なしとして扱う
+            name: str::from_utf8(name).unwrap_or(""),
+            data,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn data(&self) -> &'static [u8] {
+        self.data
+    }
+}
+
+pub fn modules(args: &Argument) -> impl Iterator<Item = Module> + '_ {
+    args.find_all::<ModuleTag>().map(Module::from_tag)
+}
+
+//名前で探す、例えば"initrd"
+pub fn find(args: &Argument, name: &str) -> Option<Module> {
+    modules(args).find(|module| module.name() == name)
+}
@@ -0,0 +1,67 @@
+use crate::console::ConsoleWriter;
+use crate::font::FontWriter;
+use crate::graphic::{PixelColor, PixelWriter};
+use crate::symbol::SymbolTable;
+use core::fmt::Write;
+use core::panic::PanicInfo;
+
+//パニックを表示するのに必要なもの、_startで設定する
+//シングルスレッドでしか使わない
+static mut PANIC_WRITER: Option<(PixelWriter, PixelColor, PixelColor)> = None;
+static mut SYMBOL_TABLE: Option<SymbolTable> = None;
+
+//呼び出し元をたどる深さの上限
+const MAX_FRAMES: usize = 16;
+
+pub fn use_pixel_writer(writer: PixelWriter, foreground: PixelColor, background: PixelColor) {
+    unsafe {
+        PANIC_WRITER = Some((writer, foreground, background));
+    }
+}
+
+pub fn use_symbol_table(symbol_table: SymbolTable) {
+    unsafe {
+        SYMBOL_TABLE = Some(symbol_table);
+    }
+}
+
+pub fn write(info: &PanicInfo) {
+    let (writer, foreground, background) = match unsafe { PANIC_WRITER } {
+        Some(panic_writer) => panic_writer,
+        None => return,
+    };
+    //画面の先頭から上書きする
+    let mut console_writer = ConsoleWriter::new(FontWriter::new(writer, foreground, background));
+    //パニック中なので表示できなくても何もしない
+    let _ = write!(console_writer, "{}\n", info);
+
+    //フレームポインタをたどる、.cargo/config.tomlでforce-frame-pointersにしている
+    //ブートローダは_startを呼ぶ前にrbpを0にしているのでそこで止まる
+    let mut rbp: u64;
+    unsafe { asm!("mov {}, rbp", out(reg) rbp) };
+    for _ in 0..MAX_FRAMES {
+        if rbp == 0 || rbp & 7 != 0 {
+            break;
+        }
+        let (next, return_addr) =
+            unsafe { (*(rbp as *const u64), *(rbp.wrapping_add(8) as *const u64)) };
+        //戻りアドレスはcallの次の命令なので1つ前で引く
+        let symbol =
+            unsafe { SYMBOL_TABLE }.and_then(|table| table.lookup(return_addr.wrapping_sub(1)));
+        let _ = match symbol {
+            Some((name, offset)) => write!(
+                console_writer,
+                "  at {:#x} {}+{:#x}\n",
+                return_addr,
+                name,
+                offset + 1
+            ),
+            None => write!(console_writer, "  at {:#x}\n", return_addr),
+        };
+        //スタックは下に伸びるので呼び出し元のフレームは上にある
+        if next <= rbp {
+            break;
+        }
+        rbp = next;
+    }
+}
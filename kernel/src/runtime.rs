@@ -0,0 +1,189 @@
+use core::ffi::c_void;
+use core::fmt;
+use core::ptr;
+
+//UEFIのEFI_STATUS、最上位ビットが立っていればエラー
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Status(pub usize);
+
+impl Status {
+    pub const SUCCESS: Status = Status(0);
+    pub const INVALID_PARAMETER: Status = Status(Status::ERROR_BIT | 2);
+    pub const BUFFER_TOO_SMALL: Status = Status(Status::ERROR_BIT | 5);
+    pub const NOT_FOUND: Status = Status(Status::ERROR_BIT | 14);
+    const ERROR_BIT: usize = !(usize::MAX >> 1);
+
+    pub fn is_error(self) -> bool {
+        self.0 & Status::ERROR_BIT != 0
+    }
+
+    fn into_result(self) -> Result<(), Status> {
+        if self.is_error() {
+            Err(self)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+//GetVariableの失敗、BUFFER_TOO_SMALLならファームウェアが書き戻した必要なバイト数を返す
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VariableError {
+    BufferTooSmall { required_size: usize },
+    Status(Status),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    //BootOrderやBootCurrentなどの変数のベンダー
+    pub const GLOBAL_VARIABLE: Guid = Guid {
+        data1: 0x8be4_df61,
+        data2: 0x93ca,
+        data3: 0x11d2,
+        data4: [0xaa, 0x0d, 0x00, 0xe0, 0x98, 0x03, 0x2b, 0x8c],
+    };
+}
+
+//EFI_TIME
+#[derive(Debug, Copy, Clone, Default)]
+#[repr(C)]
+pub struct Time {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pad1: u8,
+    pub nanosecond: u32,
+    //UTCからの分、0x07ffならローカル時刻
+    pub time_zone: i16,
+    pub daylight: u8,
+    pad2: u8,
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ResetType {
+    Cold = 0,
+    Warm,
+    Shutdown,
+}
+
+#[repr(C)]
+struct TableHeader {
+    signature: u64,
+    revision: u32,
+    header_size: u32,
+    crc32: u32,
+    reserved: u32,
+}
+
+//EFI_RUNTIME_SERVICES、使わない関数は型を付けずに並びだけ合わせる
+#[repr(C)]
+struct RuntimeServicesTable {
+    header: TableHeader,
+    get_time: extern "efiapi" fn(time: *mut Time, capabilities: *mut c_void) -> Status,
+    set_time: usize,
+    get_wakeup_time: usize,
+    set_wakeup_time: usize,
+    set_virtual_address_map: usize,
+    convert_pointer: usize,
+    get_variable: extern "efiapi" fn(
+        name: *const u16,
+        vendor: *const Guid,
+        attributes: *mut u32,
+        data_size: *mut usize,
+        data: *mut u8,
+    ) -> Status,
+    get_next_variable_name: usize,
+    set_variable: usize,
+    get_next_high_monotonic_count: usize,
+    reset_system: extern "efiapi" fn(
+        reset_type: ResetType,
+        status: Status,
+        data_size: usize,
+        data: *const u8,
+    ) -> !,
+}
+
+//ブートローダから渡されたUEFIのランタイムサービス
+//SetVirtualAddressMapは呼ばれていないのでidentity mapが残っている間だけ使える
+#[derive(Copy, Clone)]
+pub struct RuntimeServices {
+    table: &'static RuntimeServicesTable,
+}
+
+impl RuntimeServices {
+    const SIGNATURE: u64 = u64::from_le_bytes(*b"RUNTSERV");
+
+    //安全性は物理アドレスがそのまま読めることに依存
+    pub unsafe fn from_address(address: u64) -> Option<RuntimeServices> {
+        if address == 0 {
+            return None;
+        }
+        let table = &*(address as *const RuntimeServicesTable);
+        if table.header.signature != RuntimeServices::SIGNATURE {
+            return None;
+        }
+        Some(RuntimeServices { table })
+    }
+
+    pub fn get_time(&self) -> Result<Time, Status> {
+        let mut time = Time::default();
+        (self.table.get_time)(&mut time, ptr::null_mut()).into_result()?;
+        Ok(time)
+    }
+
+    //nameはNUL終端のUCS-2
+    //読めたバイト数と属性を返す
+    pub fn get_variable(
+        &self,
+        name: &[u16],
+        vendor: &Guid,
+        buffer: &mut [u8],
+    ) -> Result<(usize, u32), VariableError> {
+        if name.last() != Some(&0) {
+            return Err(VariableError::Status(Status::INVALID_PARAMETER));
+        }
+        let mut attributes = 0;
+        let mut size = buffer.len();
+        let status = (self.table.get_variable)(
+            name.as_ptr(),
+            vendor,
+            &mut attributes,
+            &mut size,
+            buffer.as_mut_ptr(),
+        );
+        if status == Status::BUFFER_TOO_SMALL {
+            return Err(VariableError::BufferTooSmall {
+                required_size: size,
+            });
+        }
+        status.into_result().map_err(VariableError::Status)?;
+        Ok((size, attributes))
+    }
+
+    pub fn reset(&self, reset_type: ResetType) -> ! {
+        (self.table.reset_system)(reset_type, Status::SUCCESS, 0, ptr::null())
+    }
+}
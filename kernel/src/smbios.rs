@@ -0,0 +1,291 @@
+use core::slice;
+use core::str;
+
+//ブートローダから渡されたSMBIOSの構造体テーブル
+//エントリポイントは2.xの"_SM_"と3.0の"_SM3_"の両方を受け付ける
+#[derive(Copy, Clone)]
+pub struct Smbios {
+    major_version: u8,
+    minor_version: u8,
+    table: &'static [u8],
+}
+
+impl Smbios {
+    const ANCHOR: [u8; 4] = *b"_SM_";
+    const ANCHOR3: [u8; 5] = *b"_SM3_";
+    const INTERMEDIATE_ANCHOR: [u8; 5] = *b"_DMI_";
+
+    //安全性は物理アドレスがそのまま読めることに依存
+    pub unsafe fn from_address(address: u64) -> Option<Smbios> {
+        if address == 0 {
+            return None;
+        }
+        //アンカーで版を見分けてから長さを読む
+        let head = slice::from_raw_parts(address as *const u8, Smbios::ANCHOR3.len());
+        if head[..5] == Smbios::ANCHOR3 {
+            Smbios::from_entry_point3(address)
+        } else if head[..4] == Smbios::ANCHOR {
+            Smbios::from_entry_point(address)
+        } else {
+            None
+        }
+    }
+
+    unsafe fn from_entry_point(address: u64) -> Option<Smbios> {
+        let entry_point = entry_point_bytes(address, 0x05, 0x1f)?;
+        if entry_point[0x10..0x15] != Smbios::INTERMEDIATE_ANCHOR
+            || !sum_is_zero(&entry_point[0x10..])
+        {
+            return None;
+        }
+        let table_length = read_u16(entry_point, 0x16) as usize;
+        let table_address = read_u32(entry_point, 0x18) as u64;
+        Some(Smbios {
+            major_version: entry_point[0x06],
+            minor_version: entry_point[0x07],
+            table: slice::from_raw_parts(table_address as *const u8, table_length),
+        })
+    }
+
+    //3.0ではテーブルの長さは上限しかわからないので終端の構造体まで読む
+    unsafe fn from_entry_point3(address: u64) -> Option<Smbios> {
+        let entry_point = entry_point_bytes(address, 0x06, 0x18)?;
+        let table_max_size = read_u32(entry_point, 0x0c) as usize;
+        let table_address = read_u64(entry_point, 0x10);
+        Some(Smbios {
+            major_version: entry_point[0x07],
+            minor_version: entry_point[0x08],
+            table: slice::from_raw_parts(table_address as *const u8, table_max_size),
+        })
+    }
+
+    pub fn version(&self) -> (u8, u8) {
+        (self.major_version, self.minor_version)
+    }
+
+    pub fn structures(&self) -> Structures {
+        Structures { rest: self.table }
+    }
+
+    //Type 1 System Information
+    pub fn system(&self) -> Option<SystemInformation> {
+        self.structures()
+            .find(|structure| structure.structure_type() == SystemInformation::TYPE)
+            .map(|structure| SystemInformation { structure })
+    }
+
+    //Type 17 Memory Device
+    pub fn memory_devices(&self) -> impl Iterator<Item = MemoryDevice> {
+        self.structures()
+            .filter(|structure| structure.structure_type() == MemoryDevice::TYPE)
+            .map(|structure| MemoryDevice { structure })
+    }
+}
+
+//長さとチェックサムを確認したエントリポイントのバイト列
+//長さの場所は2.xと3.0で違う
+unsafe fn entry_point_bytes(
+    address: u64,
+    length_offset: u64,
+    min_length: usize,
+) -> Option<&'static [u8]> {
+    let length = *((address + length_offset) as *const u8) as usize;
+    if length < min_length {
+        return None;
+    }
+    let bytes = slice::from_raw_parts(address as *const u8, length);
+    if sum_is_zero(bytes) {
+        Some(bytes)
+    } else {
+        None
+    }
+}
+
+fn sum_is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) == 0
+}
+
+//SMBIOSの値はリトルエンディアンでアラインメントもない
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut value = [0; 4];
+    value.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(value)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut value = [0; 8];
+    value.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(value)
+}
+
+//ヘッダを含む固定長部分と、その後ろのNUL終端文字列の並び
+#[derive(Copy, Clone)]
+pub struct Structure {
+    formatted: &'static [u8],
+    strings: &'static [u8],
+}
+
+impl Structure {
+    const HEADER_SIZE: usize = 4;
+    const END_OF_TABLE: u8 = 127;
+
+    pub fn structure_type(&self) -> u8 {
+        self.formatted[0]
+    }
+
+    pub fn handle(&self) -> u16 {
+        read_u16(self.formatted, 2)
+    }
+
+    //古い版の構造体は短いので範囲外はNoneにする
+    pub fn byte(&self, offset: usize) -> Option<u8> {
+        self.formatted.get(offset).copied()
+    }
+
+    pub fn word(&self, offset: usize) -> Option<u16> {
+        if offset + 2 > self.formatted.len() {
+            return None;
+        }
+        Some(read_u16(self.formatted, offset))
+    }
+
+    pub fn dword(&self, offset: usize) -> Option<u32> {
+        if offset + 4 > self.formatted.len() {
+            return None;
+        }
+        Some(read_u32(self.formatted, offset))
+    }
+
+    //文字列は1から数える、0は文字列なし
+    pub fn string(&self, index: u8) -> Option<&'static str> {
+        if index == 0 {
+            return None;
+        }
+        let bytes = self
+            .strings
+            .split(|&b| b == 0)
+            .nth(index as usize - 1)
+            .filter(|bytes| !bytes.is_empty())?;
+        str::from_utf8(bytes).ok()
+    }
+
+    //構造体の中の文字列番号が入っている場所を指定する
+    pub fn string_at(&self, offset: usize) -> Option<&'static str> {
+        self.string(self.byte(offset)?)
+    }
+}
+
+pub struct Structures {
+    rest: &'static [u8],
+}
+
+impl Iterator for Structures {
+    type Item = Structure;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.len() < Structure::HEADER_SIZE {
+            return None;
+        }
+        let length = self.rest[1] as usize;
+        if length < Structure::HEADER_SIZE || length > self.rest.len() {
+            //壊れているのでこれ以上は読まない
+            self.rest = &[];
+            return None;
+        }
+        let formatted = &self.rest[..length];
+        //文字列の並びは二つ続いたNULで終わる
+        let strings_end = match self.rest[length..].windows(2).position(|w| w == [0, 0]) {
+            Some(end) => length + end,
+            None => {
+                self.rest = &[];
+                return None;
+            }
+        };
+        let structure = Structure {
+            formatted,
+            strings: &self.rest[length..strings_end],
+        };
+        self.rest = &self.rest[strings_end + 2..];
+        if structure.structure_type() == Structure::END_OF_TABLE {
+            self.rest = &[];
+            return None;
+        }
+        Some(structure)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct SystemInformation {
+    structure: Structure,
+}
+
+impl SystemInformation {
+    const TYPE: u8 = 1;
+
+    pub fn manufacturer(&self) -> Option<&'static str> {
+        self.structure.string_at(0x04)
+    }
+
+    pub fn product_name(&self) -> Option<&'static str> {
+        self.structure.string_at(0x05)
+    }
+
+    pub fn version(&self) -> Option<&'static str> {
+        self.structure.string_at(0x06)
+    }
+
+    pub fn serial_number(&self) -> Option<&'static str> {
+        self.structure.string_at(0x07)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct MemoryDevice {
+    structure: Structure,
+}
+
+impl MemoryDevice {
+    const TYPE: u8 = 17;
+    const SIZE_UNKNOWN: u16 = 0xffff;
+    const SIZE_EXTENDED: u16 = 0x7fff;
+    //立っていればKiB単位、立っていなければMiB単位
+    const SIZE_IN_KIB: u16 = 1 << 15;
+
+    //Noneはスロットが空か大きさがわからない
+    pub fn size_in_kib(&self) -> Option<u64> {
+        let size = self.structure.word(0x0c)?;
+        match size {
+            0 | MemoryDevice::SIZE_UNKNOWN => None,
+            //32GiB以上はExtended Sizeに31ビットのMiBで入っている
+            MemoryDevice::SIZE_EXTENDED => {
+                let extended = self.structure.dword(0x1c)? & 0x7fff_ffff;
+                Some(extended as u64 * 1024)
+            }
+            _ if size & MemoryDevice::SIZE_IN_KIB != 0 => {
+                Some((size & !MemoryDevice::SIZE_IN_KIB) as u64)
+            }
+            _ => Some(size as u64 * 1024),
+        }
+    }
+
+    pub fn device_locator(&self) -> Option<&'static str> {
+        self.structure.string_at(0x10)
+    }
+
+    pub fn bank_locator(&self) -> Option<&'static str> {
+        self.structure.string_at(0x11)
+    }
+
+    //MT/s、0はわからない
+    pub fn speed(&self) -> Option<u16> {
+        self.structure.word(0x15).filter(|&speed| speed != 0)
+    }
+
+    pub fn manufacturer(&self) -> Option<&'static str> {
+        self.structure.string_at(0x17)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::arg;
+use core::mem;
+use core::ptr;
+use core::slice;
+use core::str;
+
+//ブートローダから渡された.symtabでアドレスを関数名に変換する
+#[derive(Copy, Clone)]
+pub struct SymbolTable {
+    symbols: &'static [u8],
+    strings: &'static [u8],
+    load_bias: u64,
+}
+
+impl SymbolTable {
+    pub fn new(symbol_table: arg::SymbolTable) -> SymbolTable {
+        //安全性はブートローダに依存
+        let symbols = if symbol_table.symbols.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(symbol_table.symbols, symbol_table.symbols_size) }
+        };
+        let strings = if symbol_table.strings.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(symbol_table.strings, symbol_table.strings_size) }
+        };
+        SymbolTable {
+            symbols,
+            strings,
+            load_bias: symbol_table.load_bias,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len() / mem::size_of::<Symbol>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    //addrを含む関数の名前と先頭からのオフセット
+    pub fn lookup(&self, addr: u64) -> Option<(&'static str, u64)> {
+        let addr = addr.wrapping_sub(self.load_bias);
+        for i in 0..self.len() {
+            let symbol = self.symbol(i);
+            if !symbol.is_function() {
+                continue;
+            }
+            let start = symbol.st_value;
+            let end = start.saturating_add(symbol.st_size.max(1));
+            if addr >= start && addr < end {
+                let name = self.name(symbol.st_name).unwrap_or("?");
+                return Some((name, addr - start));
+            }
+        }
+        None
+    }
+
+    fn symbol(&self, index: usize) -> Symbol {
+        let offset = index * mem::size_of::<Symbol>();
+        //アラインメントは保証されないのでread_unaligned
+        unsafe { ptr::read_unaligned(self.symbols[offset..].as_ptr() as *const Symbol) }
+    }
+
+    fn name(&self, offset: u32) -> Option<&'static str> {
+        let bytes = self.strings.get(offset as usize..)?;
+        let len = bytes.iter().position(|&b| b == 0)?;
+        str::from_utf8(&bytes[..len]).ok()
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct Symbol {
+    st_name: u32,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+    st_value: u64,
+    st_size: u64,
+}
+
+impl Symbol {
+    const TYPE_FUNC: u8 = 2;
+
+    fn is_function(&self) -> bool {
+        self.st_info & 0xf == Symbol::TYPE_FUNC
+    }
+}
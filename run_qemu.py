@@ -1,3 +1,5 @@
 import subprocess as proc
 
-proc.call('qemu-system-x86_64 -m 512M -drive if=pflash,format=raw,readonly,file=./OVMF_CODE.fd -drive if=pflash,format=raw,file=./OVMF_VARS.fd -drive if=ide,index=0,media=disk,format=raw,file=disk.img -device nec-usb-xhci,id=xhci -device usb-mouse -device usb-kbd -monitor stdio', shell=True)
+# ブートローダのパニックはCOM1にも出るので端末に表示する
+# モニタは同じ端末でCtrl-a cを押すと切り替わる
+proc.call('qemu-system-x86_64 -m 512M -drive if=pflash,format=raw,readonly,file=./OVMF_CODE.fd -drive if=pflash,format=raw,file=./OVMF_VARS.fd -drive if=ide,index=0,media=disk,format=raw,file=disk.img -device nec-usb-xhci,id=xhci -device usb-mouse -device usb-kbd -serial mon:stdio', shell=True)
@@ -0,0 +1,796 @@
+//! 東雲フォントのASCII部分
+//!
+//! ブートローダとカーネルで同じ字形を使うために分けている。
+//! 1文字は8x16ドットで、各行の最上位ビットが左端になる。
+#![no_std]
+
+pub const WIDTH: usize = 8;
+pub const HEIGHT: usize = 16;
+pub const MAX: usize = 0x80;
+
+//glyph:字形
+pub type Glyph = [u8; HEIGHT];
+
+//非対応文字は■
+pub const UNSUPPORTED: Glyph = [0b11111111; HEIGHT];
+
+//ASCII以外はNone
+pub fn glyph(c: char) -> Option<&'static Glyph> {
+    GLYPHS.get(c as u32 as usize)
+}
+
+pub fn is_draw_bit(glyph: &Glyph, x: usize, y: usize) -> bool {
+    //範囲エラーを避けたいのでget
+    ((glyph.get(y).unwrap_or(&0) << x) & 0b1000_0000) == 0b1000_0000
+}
+
+pub const GLYPHS: [Glyph; MAX] = [
+    //0x00
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x01
+    [
+        0b00010000, 0b00010000, 0b00111000, 0b00111000, 0b01111100, 0b01111100, 0b11111110,
+        0b11111110, 0b01111100, 0b01111100, 0b00111000, 0b00111000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x02
+    [
+        0b01010101, 0b10101010, 0b01010101, 0b10101010, 0b01010101, 0b10101010, 0b01010101,
+        0b10101010, 0b01010101, 0b10101010, 0b01010101, 0b10101010, 0b01010101, 0b10101010,
+        0b00000000, 0b00000000,
+    ],
+    //0x03
+    [
+        0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b11111000, 0b10001000, 0b10001000,
+        0b10001000, 0b00000000, 0b00111110, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00001000, 0b00001000,
+    ],
+    //0x04
+    [
+        0b00000000, 0b11111000, 0b10000000, 0b10000000, 0b11110000, 0b10000000, 0b10000000,
+        0b10000000, 0b00111110, 0b00100000, 0b00100000, 0b00111100, 0b00100000, 0b00100000,
+        0b00100000, 0b00000000,
+    ],
+    //0x05
+    [
+        0b00000000, 0b01110000, 0b10001000, 0b10000000, 0b10000000, 0b10000000, 0b10001000,
+        0b01110000, 0b00000000, 0b00111100, 0b00100010, 0b00100010, 0b00111100, 0b00101000,
+        0b00100100, 0b00100010,
+    ],
+    //0x06
+    [
+        0b00000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
+        0b11111000, 0b00000000, 0b00111110, 0b00100000, 0b00100000, 0b00111100, 0b00100000,
+        0b00100000, 0b00100000,
+    ],
+    //0x07
+    [
+        0b00000000, 0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x08
+    [
+        0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b11111110,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00000000, 0b11111110, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x09
+    [
+        0b00000000, 0b10000100, 0b11000100, 0b10100100, 0b10100100, 0b10010100, 0b10010100,
+        0b10001100, 0b10000100, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
+        0b00100000, 0b00111110,
+    ],
+    //0x0a
+    [
+        0b00000000, 0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b01010000, 0b01010000,
+        0b00100000, 0b00000000, 0b00111110, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00001000, 0b00000000,
+    ],
+    //0x0b
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b11110000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x0c
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x0d
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00011111, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x0e
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00011111, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x0f
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b11111111, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x10
+    [
+        0b00000000, 0b00000000, 0b11111111, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x11
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x12
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b11111111, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x13
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x14
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b11111111, 0b00000000,
+    ],
+    //0x15
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00011111, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x16
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x17
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b11111111, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x18
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b11111111, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x19
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x1a
+    [
+        0b00000000, 0b00000000, 0b00000010, 0b00001100, 0b00110000, 0b11000000, 0b00110000,
+        0b00001100, 0b00000010, 0b00000000, 0b11111110, 0b00000000, 0b11111110, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x1b
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b10000000, 0b01100000, 0b00011000, 0b00000110,
+        0b00011000, 0b01100000, 0b10000000, 0b11111110, 0b00000000, 0b11111110, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x1c
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b11111110, 0b00100100, 0b00100100, 0b00100100, 0b00100100, 0b01000100, 0b10000100,
+        0b00000000, 0b00000000,
+    ],
+    //0x1d
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000010, 0b00000100, 0b00001000,
+        0b11111110, 0b00010000, 0b11111110, 0b00100000, 0b01000000, 0b10000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x1e
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00001100, 0b00010010, 0b00010000,
+        0b00010000, 0b00010000, 0b01111100, 0b00010000, 0b00010000, 0b00111100, 0b01010010,
+        0b00100000, 0b00000000,
+    ],
+    //0x1f
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00010000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x20
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x21
+    [
+        0b00000000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00000000, 0b00000000, 0b00010000,
+        0b00010000, 0b00000000,
+    ],
+    //0x22
+    [
+        0b01101100, 0b00100100, 0b00100100, 0b01001000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x23
+    [
+        0b00000000, 0b00010010, 0b00010010, 0b00010010, 0b01111111, 0b00100100, 0b00100100,
+        0b00100100, 0b00100100, 0b00100100, 0b11111110, 0b01001000, 0b01001000, 0b01001000,
+        0b01001000, 0b00000000,
+    ],
+    //0x24
+    [
+        0b00010000, 0b00111000, 0b01010100, 0b10010010, 0b10010010, 0b10010000, 0b01010000,
+        0b00111000, 0b00010100, 0b00010010, 0b10010010, 0b10010010, 0b01010100, 0b00111000,
+        0b00010000, 0b00010000,
+    ],
+    //0x25
+    [
+        0b00000001, 0b01100001, 0b10010010, 0b10010010, 0b10010100, 0b10010100, 0b01101000,
+        0b00001000, 0b00010000, 0b00010110, 0b00101001, 0b00101001, 0b01001001, 0b01001001,
+        0b10000110, 0b10000000,
+    ],
+    //0x26
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b01000100, 0b00101000, 0b00010000,
+        0b00110000, 0b01001010, 0b10001010, 0b10000100, 0b10000100, 0b01001010, 0b00110001,
+        0b00000000, 0b00000000,
+    ],
+    //0x27
+    [
+        0b01100000, 0b00100000, 0b00100000, 0b01000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x28
+    [
+        0b00000000, 0b00000010, 0b00000100, 0b00001000, 0b00001000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00001000, 0b00001000,
+        0b00000100, 0b00000010,
+    ],
+    //0x29
+    [
+        0b00000000, 0b01000000, 0b00100000, 0b00010000, 0b00010000, 0b00001000, 0b00001000,
+        0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00010000, 0b00010000,
+        0b00100000, 0b01000000,
+    ],
+    //0x2a
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00010000, 0b10010010, 0b01010100,
+        0b00111000, 0b01010100, 0b10010010, 0b00010000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x2b
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b11111110, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x2c
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b00100000,
+        0b00100000, 0b01000000,
+    ],
+    //0x2d
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b11111110, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x2e
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b01100000,
+        0b00000000, 0b00000000,
+    ],
+    //0x2f
+    [
+        0b00000000, 0b00000010, 0b00000010, 0b00000100, 0b00000100, 0b00001000, 0b00001000,
+        0b00010000, 0b00010000, 0b00100000, 0b00100000, 0b01000000, 0b01000000, 0b10000000,
+        0b10000000, 0b00000000,
+    ],
+    //0x30
+    [
+        0b00000000, 0b00011000, 0b00100100, 0b00100100, 0b01000010, 0b01000010, 0b01000010,
+        0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b00100100, 0b00100100, 0b00011000,
+        0b00000000, 0b00000000,
+    ],
+    //0x31
+    [
+        0b00000000, 0b00010000, 0b00010000, 0b00110000, 0b01010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x32
+    [
+        0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01000010, 0b00000010, 0b00000100,
+        0b00001000, 0b00010000, 0b00100000, 0b00100000, 0b01000000, 0b01000000, 0b01111110,
+        0b00000000, 0b00000000,
+    ],
+    //0x33
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b00000010, 0b00000100,
+        0b00111000, 0b00000100, 0b00000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x34
+    [
+        0b00000000, 0b00001000, 0b00011000, 0b00011000, 0b00101000, 0b00101000, 0b01001000,
+        0b01001000, 0b10001000, 0b11111110, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00000000, 0b00000000,
+    ],
+    //0x35
+    [
+        0b00000000, 0b01111100, 0b01000000, 0b01000000, 0b01000000, 0b10111000, 0b11000100,
+        0b10000010, 0b00000010, 0b00000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x36
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b01000000, 0b10000000, 0b10000000, 0b10111000,
+        0b11000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x37
+    [
+        0b00000000, 0b11111110, 0b00000010, 0b00000100, 0b00000100, 0b00001000, 0b00001000,
+        0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00000000,
+    ],
+    //0x38
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010, 0b01000100,
+        0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x39
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
+        0b01000110, 0b00111010, 0b00000010, 0b00000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3a
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3b
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00001000, 0b00001000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3c
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000010, 0b00000100, 0b00001000, 0b00010000,
+        0b00100000, 0b00100000, 0b00010000, 0b00001000, 0b00000100, 0b00000010, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3d
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111110, 0b00000000,
+        0b00000000, 0b00000000, 0b11111110, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3e
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b01000000, 0b00100000, 0b00010000, 0b00001000,
+        0b00000100, 0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x3f
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000010, 0b00000100,
+        0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00000000, 0b00000000, 0b00010000,
+        0b00010000, 0b00000000,
+    ],
+    //0x40
+    [
+        0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01011010, 0b10110101, 0b10100101,
+        0b10100101, 0b10100101, 0b10011010, 0b01000000, 0b01000000, 0b00100010, 0b00011100,
+        0b00000000, 0b00000000,
+    ],
+    //0x41
+    [
+        0b00000000, 0b00010000, 0b00010000, 0b00101000, 0b00101000, 0b00101000, 0b01000100,
+        0b01000100, 0b01000100, 0b01111100, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x42
+    [
+        0b00000000, 0b11110000, 0b10001000, 0b10000100, 0b10000100, 0b10000100, 0b10001000,
+        0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010, 0b10000100, 0b11111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x43
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b01000010, 0b10000000, 0b10000000, 0b10000000,
+        0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b01000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x44
+    [
+        0b00000000, 0b11110000, 0b10001000, 0b10000100, 0b10000100, 0b10000010, 0b10000010,
+        0b10000010, 0b10000010, 0b10000010, 0b10000100, 0b10000100, 0b10001000, 0b11110000,
+        0b00000000, 0b00000000,
+    ],
+    //0x45
+    [
+        0b00000000, 0b11111110, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
+        0b11111100, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b11111110,
+        0b00000000, 0b00000000,
+    ],
+    //0x46
+    [
+        0b00000000, 0b11111110, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
+        0b11111100, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x47
+    [
+        0b00000000, 0b00011000, 0b00100100, 0b01000010, 0b01000000, 0b10000000, 0b10000000,
+        0b10001110, 0b10000010, 0b10000010, 0b10000010, 0b01000010, 0b01100110, 0b00011010,
+        0b00000000, 0b00000000,
+    ],
+    //0x48
+    [
+        0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
+        0b11111110, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x49
+    [
+        0b00000000, 0b00111000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x4a
+    [
+        0b00000000, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00000010,
+        0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b01000010, 0b00100100, 0b00011000,
+        0b00000000, 0b00000000,
+    ],
+    //0x4b
+    [
+        0b00000000, 0b01000010, 0b01000010, 0b01000100, 0b01000100, 0b01001000, 0b01011000,
+        0b01101000, 0b01100100, 0b01000100, 0b01000010, 0b01000010, 0b01000001, 0b01000001,
+        0b00000000, 0b00000000,
+    ],
+    //0x4c
+    [
+        0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000,
+        0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01111110,
+        0b00000000, 0b00000000,
+    ],
+    //0x4d
+    [
+        0b00000000, 0b10000010, 0b10000010, 0b11000110, 0b11000110, 0b11000110, 0b10101010,
+        0b10101010, 0b10101010, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x4e
+    [
+        0b00000000, 0b10000010, 0b11000010, 0b11000010, 0b10100010, 0b10100010, 0b10010010,
+        0b10010010, 0b10010010, 0b10001010, 0b10001010, 0b10000110, 0b10000110, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x4f
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x50
+    [
+        0b00000000, 0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010, 0b10000100,
+        0b11111000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x51
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b01000100, 0b10000010, 0b10000010, 0b10000010,
+        0b10000010, 0b10000010, 0b10000010, 0b10111010, 0b01000100, 0b01000100, 0b00111000,
+        0b00001000, 0b00000110,
+    ],
+    //0x52
+    [
+        0b00000000, 0b11111000, 0b10000100, 0b10000010, 0b10000010, 0b10000010, 0b10000100,
+        0b11111000, 0b10001000, 0b10000100, 0b10000100, 0b10000100, 0b10000010, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x53
+    [
+        0b00000000, 0b00111000, 0b01000100, 0b10000010, 0b10000010, 0b10000000, 0b01100000,
+        0b00011000, 0b00000100, 0b00000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x54
+    [
+        0b00000000, 0b11111110, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x55
+    [
+        0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x56
+    [
+        0b00000000, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b01000100,
+        0b01000100, 0b00101000, 0b00101000, 0b00101000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x57
+    [
+        0b00000000, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010,
+        0b10101010, 0b10101010, 0b01101100, 0b01000100, 0b01000100, 0b01000100, 0b01000100,
+        0b00000000, 0b00000000,
+    ],
+    //0x58
+    [
+        0b00000000, 0b10000010, 0b01000100, 0b01000100, 0b00101000, 0b00101000, 0b00010000,
+        0b00101000, 0b00101000, 0b00101000, 0b01000100, 0b01000100, 0b10000010, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x59
+    [
+        0b00000000, 0b10000010, 0b10000010, 0b01000100, 0b01000100, 0b01000100, 0b00101000,
+        0b00101000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x5a
+    [
+        0b00000000, 0b11111110, 0b00000100, 0b00000100, 0b00001000, 0b00001000, 0b00010000,
+        0b00010000, 0b00100000, 0b00100000, 0b01000000, 0b01000000, 0b10000000, 0b11111110,
+        0b00000000, 0b00000000,
+    ],
+    //0x5b
+    [
+        0b00011110, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00011110,
+    ],
+    //0x5c
+    [
+        0b00000000, 0b10000000, 0b10000000, 0b01000000, 0b01000000, 0b00100000, 0b00100000,
+        0b00010000, 0b00010000, 0b00001000, 0b00001000, 0b00000100, 0b00000100, 0b00000010,
+        0b00000010, 0b00000000,
+    ],
+    //0x5d
+    [
+        0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b11110000,
+    ],
+    //0x5e
+    [
+        0b00010000, 0b00101000, 0b01000100, 0b10000010, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x5f
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b11111110, 0b00000000,
+    ],
+    //0x60
+    [
+        0b00110000, 0b00100000, 0b00100000, 0b00010000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x61
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111100, 0b01000010,
+        0b00000010, 0b00111110, 0b01000010, 0b10000010, 0b10000010, 0b10000110, 0b01111010,
+        0b00000000, 0b00000000,
+    ],
+    //0x62
+    [
+        0b00000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10111000, 0b11000100,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b11000100, 0b10111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x63
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000, 0b01000100,
+        0b10000010, 0b10000000, 0b10000000, 0b10000000, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x64
+    [
+        0b00000000, 0b00000010, 0b00000010, 0b00000010, 0b00000010, 0b00111010, 0b01000110,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000110, 0b00111010,
+        0b00000000, 0b00000000,
+    ],
+    //0x65
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000, 0b01000100,
+        0b10000010, 0b10000010, 0b11111110, 0b10000000, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x66
+    [
+        0b00000000, 0b00001100, 0b00010000, 0b00010000, 0b00010000, 0b01111100, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x67
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111011, 0b01000100,
+        0b01000100, 0b01000100, 0b00111000, 0b01000000, 0b01111000, 0b10000100, 0b10000010,
+        0b10000010, 0b01111100,
+    ],
+    //0x68
+    [
+        0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01011100, 0b01100010,
+        0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x69
+    [
+        0b00000000, 0b00010000, 0b00010000, 0b00000000, 0b00000000, 0b00110000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x6a
+    [
+        0b00000000, 0b00001000, 0b00001000, 0b00000000, 0b00000000, 0b00011000, 0b00001000,
+        0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00010000, 0b01100000,
+    ],
+    //0x6b
+    [
+        0b00000000, 0b01000000, 0b01000000, 0b01000000, 0b01000000, 0b01000010, 0b01000100,
+        0b01001000, 0b01010000, 0b01101000, 0b01000100, 0b01000100, 0b01000010, 0b01000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x6c
+    [
+        0b00000000, 0b00110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x6d
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11101100, 0b10010010,
+        0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010, 0b10010010,
+        0b00000000, 0b00000000,
+    ],
+    //0x6e
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01011100, 0b01100010,
+        0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x6f
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111000, 0b01000100,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000100, 0b00111000,
+        0b00000000, 0b00000000,
+    ],
+    //0x70
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10111000, 0b11000100,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b11000100, 0b10111000, 0b10000000,
+        0b10000000, 0b10000000,
+    ],
+    //0x71
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111010, 0b01000110,
+        0b10000010, 0b10000010, 0b10000010, 0b10000010, 0b01000110, 0b00111010, 0b00000010,
+        0b00000010, 0b00000010,
+    ],
+    //0x72
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00101100, 0b00110000,
+        0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
+        0b00000000, 0b00000000,
+    ],
+    //0x73
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00111100, 0b01000010,
+        0b01000000, 0b01100000, 0b00011000, 0b00000110, 0b00000010, 0b01000010, 0b00111100,
+        0b00000000, 0b00000000,
+    ],
+    //0x74
+    [
+        0b00000000, 0b00000000, 0b00010000, 0b00010000, 0b00010000, 0b01111100, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00001100,
+        0b00000000, 0b00000000,
+    ],
+    //0x75
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01000010, 0b01000010,
+        0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000110, 0b00111010,
+        0b00000000, 0b00000000,
+    ],
+    //0x76
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010, 0b10000010,
+        0b10000010, 0b01000100, 0b01000100, 0b00101000, 0b00101000, 0b00010000, 0b00010000,
+        0b00000000, 0b00000000,
+    ],
+    //0x77
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10010010, 0b10010010,
+        0b10010010, 0b10010010, 0b10101010, 0b10101010, 0b01000100, 0b01000100, 0b01000100,
+        0b00000000, 0b00000000,
+    ],
+    //0x78
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010, 0b01000100,
+        0b00101000, 0b00101000, 0b00010000, 0b00101000, 0b00101000, 0b01000100, 0b10000010,
+        0b00000000, 0b00000000,
+    ],
+    //0x79
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10000010, 0b10000010,
+        0b01000100, 0b01000100, 0b00101000, 0b00101000, 0b00011000, 0b00010000, 0b00010000,
+        0b00100000, 0b11000000,
+    ],
+    //0x7a
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01111110, 0b00000100,
+        0b00001000, 0b00001000, 0b00010000, 0b00010000, 0b00100000, 0b01000000, 0b11111110,
+        0b00000000, 0b00000000,
+    ],
+    //0x7b
+    [
+        0b00000100, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00010000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000, 0b00001000,
+        0b00001000, 0b00000100,
+    ],
+    //0x7c
+    [
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000,
+        0b00010000, 0b00010000,
+    ],
+    //0x7d
+    [
+        0b01000000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
+        0b00010000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000,
+        0b00100000, 0b01000000,
+    ],
+    //0x7e
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b10010010, 0b00001100, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+    //0x7f
+    [
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000,
+        0b00000000, 0b00000000,
+    ],
+];